@@ -1,12 +1,33 @@
 # This script demonstrates how to combine two model.numshb files into a single file.
-from ssbh_data_py import mesh_data
+from ssbh_data_py import mesh_data, modl_data
 
 mesh_a = mesh_data.read_mesh("model_a.numshb")
 mesh_b = mesh_data.read_mesh("model_b.numshb")
+modl = modl_data.read_modl("model_a.numdlb")
 
 # Add all the mesh objects from B to A.
 for mesh_b_object in mesh_b.objects:
     mesh_a.objects.append(mesh_b_object)
 
+# Objects with the same name need unique subindices.
+# The modl entries are updated to keep the same materials.
+# Objects from B need modl entries added separately if their names are new.
+# Objects from B with the same name and subindex as an object from A with a modl entry
+# raise an error since the entry can't be matched to a single object.
+mesh_a.update_subindices(modl)
+
+# Objects can also be combined into a single object.
+# Attributes are matched by name and bone influences by bone name.
+# The skeleton is needed to convert objects with a parent bone to vertex skinning.
+# combined = mesh_data.merge_mesh_objects(mesh_a.objects, skel)
+
+# Split objects keep the original name and subindex.
+# Update the subindices again after adding the parts to the mesh.
+# The parts don't have modl entries yet, so add an entry for each part afterwards.
+# parts = combined.split_by_connectivity()
+# mesh_a.objects = parts
+# mesh_a.update_subindices()
+
 # ssbh_data_py will recalculate the bounding data and rebuild mesh buffers.
 mesh_a.save("model_combined.numshb")
+modl.save("model_combined.numdlb")
@@ -2,6 +2,7 @@
 # Usage: python parent_bone_to_weights.py input.numshb input.nusktb output.numshb
 
 import sys
+import numpy
 import ssbh_data_py
 
 mesh_path = sys.argv[1]
@@ -16,17 +17,13 @@ for mesh_object in mesh.objects:
     # There are no influences, so the object uses a parent bone.
     if len(mesh_object.bone_influences) == 0:
         # Use the parent bone to create vertex weights.
+        # The weights are stored as arrays in influence_arrays instead of bone_influences.
         vertex_count = len(mesh_object.positions[0].data)
-        vertex_weights = [
-            ssbh_data_py.mesh_data.VertexWeight(i, 1.0) for i in range(vertex_count)
-        ]
-        influence = ssbh_data_py.mesh_data.BoneInfluence(
-            mesh_object.parent_bone_name, vertex_weights
-        )
+        weights = numpy.ones((vertex_count, 1), dtype=numpy.float32)
 
         # Adding an influence enables vertex skinning.
         # This means that the mesh object will no longer appear at the same location as the parent bone.
-        mesh_object.bone_influences.append(influence)
+        mesh_object.set_weight_matrix(weights, [mesh_object.parent_bone_name])
 
         # Find the bone in the skeleton associated with this mesh object.
         parent_bone = None
@@ -22,7 +22,7 @@ fn generate_pyi_file(file_path: &str, functions: &[&str], class_definitions: &[S
     writeln!(&mut f, "# Changes made to this file will not be saved.").unwrap();
     writeln!(
         &mut f,
-        "from typing import List, Tuple, Any, Optional, Union, ClassVar"
+        "from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal"
     )
     .unwrap();
     writeln!(&mut f, "import numpy").unwrap();
@@ -74,6 +74,21 @@ fn main() {
         ],
     );
 
+    generate_pyi_file(
+        "ssbh_data_py/gltf.pyi",
+        &[
+            "def export_gltf(
+    path: str,
+    modl: ModlData,
+    mesh: MeshData,
+    skel: SkelData,
+    matl: MatlData,
+    anim: Optional[AnimData] = None) -> list[str]: ...",
+            "def import_gltf(path: str) -> tuple[MeshData, SkelData, AnimData, list[str]]: ...",
+        ],
+        &[],
+    );
+
     generate_pyi_file(
         "ssbh_data_py/modl_data.pyi",
         &["def read_modl(path: str) -> ModlData: ..."],
@@ -91,10 +106,20 @@ fn main() {
         &[SkelData::pyi(), BoneData::pyi(), BillboardType::pyi()],
     );
 
+    let attribute_name = ssbh_data_py_types::mesh_data::attribute_name_pyi();
     generate_pyi_file(
         "ssbh_data_py/mesh_data.pyi",
         &[
+            &attribute_name,
             "def read_mesh(path: str) -> MeshData: ...",
+            "def attribute_names() -> dict[str, list[str]]: ...",
+            "def color_set_scale(name: str) -> Optional[float]: ...",
+            "def export_obj(mesh: MeshData, path: str) -> None: ...",
+            "def import_obj(path: str) -> MeshData: ...",
+            "def export_smd(mesh: MeshData, skel: SkelData, path: str) -> None: ...",
+            "def import_smd(path: str) -> tuple[MeshData, SkelData]: ...",
+            "def merge_mesh_objects(
+    objects: list[MeshObjectData], skel: Optional[SkelData] = None) -> MeshObjectData: ...",
             "def transform_points(
     points: numpy.ndarray, transform: numpy.ndarray) -> numpy.ndarray: ...",
             "def transform_vectors(
@@ -109,13 +134,30 @@ fn main() {
             MeshObjectData::pyi(),
             AttributeData::pyi(),
             BoneInfluence::pyi(),
+            InfluenceArrays::pyi(),
             VertexWeight::pyi(),
         ],
     );
 
     generate_pyi_file(
         "ssbh_data_py/anim_data.pyi",
-        &["def read_anim(path: str) -> AnimData: ..."],
+        &[
+            "def read_anim(path: str) -> AnimData: ...",
+            "def export_smd_anim(anim: AnimData, skel: SkelData, path: str) -> None: ...",
+            "def import_smd_anim(path: str) -> AnimData: ...",
+            "def blend_anims(
+    anim_a: AnimData, anim_b: AnimData, weights: list[float]) -> AnimData: ...",
+            "def apply_additive_anim(
+    base: AnimData, additive: AnimData, reference_frame: float = 0.0, weight: float = 1.0) -> AnimData: ...",
+            "def concatenate_anims(
+    anims: list[AnimData], crossfade_frames: int = 0) -> AnimData: ...",
+            "def evaluate_material_anim(
+    anim: AnimData, matl: MatlData, frame: float) -> tuple[MatlData, list[str]]: ...",
+            "def visible_mesh_objects(
+    anim: AnimData, mesh: MeshData, frame: float) -> list[MeshObjectData]: ...",
+            "def create_visibility_group(
+    visible_names: list[set[str]], node_names: Optional[list[str]] = None) -> GroupData: ...",
+        ],
         &[
             AnimData::pyi(),
             GroupData::pyi(),
@@ -125,6 +167,7 @@ fn main() {
             Transform::pyi(),
             ssbh_data_py_types::anim_data::anim_data::UvTransform::pyi(),
             TransformFlags::pyi(),
+            CameraValues::pyi(),
         ],
     );
 
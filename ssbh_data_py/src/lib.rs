@@ -8,6 +8,9 @@ mod ssbh_data_py {
     #[pymodule_export]
     use ssbh_data_py_types::anim_data::anim_data;
 
+    #[pymodule_export]
+    use ssbh_data_py_types::gltf::gltf;
+
     #[pymodule_export]
     use ssbh_data_py_types::hlpb_data::hlpb_data;
 
@@ -32,6 +35,9 @@ mod ssbh_data_py {
     #[pymodule_export]
     use anim_data::AnimDataError;
 
+    #[pymodule_export]
+    use gltf::GltfError;
+
     #[pymodule_export]
     use hlpb_data::HlpbDataError;
 
@@ -6,3 +6,4 @@ from .adj_data import *
 from .matl_data import *
 from .meshex_data import *
 from .hlpb_data import *
+from .gltf import *
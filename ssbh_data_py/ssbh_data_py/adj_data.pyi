@@ -1,6 +1,6 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
@@ -10,10 +10,22 @@ def read_adj(path: str) -> AdjData: ...
 class AdjData:
     entries: list[AdjEntryData]
 
-    def __init__(self,) -> None: ...
-    
+    def __init__(self) -> None: ...
+
     def save(self, path: str) -> None: ...
 
+    @staticmethod
+    def from_mesh(
+        mesh: MeshData,
+        object_indices: Optional[list[int]] = None,
+    ) -> AdjData: ...
+
+    def update(
+        self,
+        mesh: MeshData,
+        object_indices: Optional[list[int]] = None,
+    ) -> list[int]: ...
+
 
 class AdjEntryData:
     mesh_object_index: int
@@ -21,9 +33,11 @@ class AdjEntryData:
 
     def __init__(
         self,
-        mesh_object_index: int
+        mesh_object_index: int,
     ) -> None: ...
-    
+
     @staticmethod
-    def from_mesh_object(mesh_object_index: int,
-                         mesh_object: MeshObjectData) -> AdjEntryData: ...
+    def from_mesh_object(
+        mesh_object_index: int,
+        mesh_object: MeshObjectData,
+    ) -> AdjEntryData: ...
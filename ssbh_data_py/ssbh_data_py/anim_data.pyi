@@ -1,12 +1,42 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
 def read_anim(path: str) -> AnimData: ...
 
 
+def export_smd_anim(anim: AnimData, skel: SkelData, path: str) -> None: ...
+
+
+def import_smd_anim(path: str) -> AnimData: ...
+
+
+def blend_anims(
+    anim_a: AnimData, anim_b: AnimData, weights: list[float]) -> AnimData: ...
+
+
+def apply_additive_anim(
+    base: AnimData, additive: AnimData, reference_frame: float = 0.0, weight: float = 1.0) -> AnimData: ...
+
+
+def concatenate_anims(
+    anims: list[AnimData], crossfade_frames: int = 0) -> AnimData: ...
+
+
+def evaluate_material_anim(
+    anim: AnimData, matl: MatlData, frame: float) -> tuple[MatlData, list[str]]: ...
+
+
+def visible_mesh_objects(
+    anim: AnimData, mesh: MeshData, frame: float) -> list[MeshObjectData]: ...
+
+
+def create_visibility_group(
+    visible_names: list[set[str]], node_names: Optional[list[str]] = None) -> GroupData: ...
+
+
 class AnimData:
     major_version: int
     minor_version: int
@@ -21,6 +51,19 @@ class AnimData:
 
     def save(self, path: str) -> None: ...
 
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
+    def reduce(
+        self,
+        tolerance: float = 0.0001,
+        smoothing_window: int = 0,
+        smoothing_filter: str = 'box',
+    ) -> None: ...
+
 
 class GroupData:
     group_type: GroupType
@@ -76,6 +119,13 @@ class TrackData:
                       list[float], list[bool], list[int], list[list[float]]] = []
     ) -> None: ...
 
+    def reduce(
+        self,
+        tolerance: float = 0.0001,
+        smoothing_window: int = 0,
+        smoothing_filter: str = 'box',
+    ) -> None: ...
+
 
 class Transform:
     scale: list[float]
@@ -120,3 +170,27 @@ class TransformFlags:
         override_scale: bool = False,
         override_compensate_scale: bool = False
     ) -> None: ...
+
+
+class CameraValues:
+    position: numpy.ndarray
+    target: numpy.ndarray
+    up: numpy.ndarray
+    field_of_view: numpy.ndarray
+    near_clip: numpy.ndarray
+    far_clip: numpy.ndarray
+
+    def __init__(
+        self,
+        position: numpy.ndarray,
+        target: numpy.ndarray,
+        up: numpy.ndarray,
+        field_of_view: numpy.ndarray,
+        near_clip: numpy.ndarray,
+        far_clip: numpy.ndarray,
+    ) -> None: ...
+
+    @staticmethod
+    def from_anim(anim: AnimData) -> CameraValues: ...
+
+    def to_anim(self) -> AnimData: ...
@@ -0,0 +1,19 @@
+# File automatically generated by build.rs.
+# Changes made to this file will not be saved.
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
+import numpy
+
+
+def export_gltf(
+    path: str,
+    modl: ModlData,
+    mesh: MeshData,
+    skel: SkelData,
+    matl: MatlData,
+    anim: Optional[AnimData] = None) -> list[str]: ...
+
+
+def import_gltf(path: str) -> tuple[MeshData, SkelData, AnimData, list[str]]: ...
+
+
+
@@ -1,6 +1,6 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
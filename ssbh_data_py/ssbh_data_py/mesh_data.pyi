@@ -1,12 +1,37 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
+AttributeName = Literal['Position0', 'Normal0', 'Binormal0', 'Tangent0', 'map1', 'uvSet', 'uvSet1', 'uvSet2', 'bake1', 'colorSet1', 'colorSet2', 'colorSet2_1', 'colorSet2_2', 'colorSet2_3', 'colorSet3', 'colorSet4', 'colorSet5', 'colorSet6', 'colorSet7']
+
+
 def read_mesh(path: str) -> MeshData: ...
 
 
+def attribute_names() -> dict[str, list[str]]: ...
+
+
+def color_set_scale(name: str) -> Optional[float]: ...
+
+
+def export_obj(mesh: MeshData, path: str) -> None: ...
+
+
+def import_obj(path: str) -> MeshData: ...
+
+
+def export_smd(mesh: MeshData, skel: SkelData, path: str) -> None: ...
+
+
+def import_smd(path: str) -> tuple[MeshData, SkelData]: ...
+
+
+def merge_mesh_objects(
+    objects: list[MeshObjectData], skel: Optional[SkelData] = None) -> MeshObjectData: ...
+
+
 def transform_points(
     points: numpy.ndarray, transform: numpy.ndarray) -> numpy.ndarray: ...
 
@@ -36,6 +61,41 @@ class MeshData:
 
     def save(self, path: str) -> None: ...
 
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
+    def update_subindices(
+        self,
+        modl: Optional[ModlData] = None,
+    ) -> None: ...
+
+    def validate_attributes(self) -> None: ...
+
+    def mirror(
+        self,
+        axis: str = 'x',
+        rename_bones: bool = True,
+        skel: Optional[SkelData] = None,
+    ) -> MeshData: ...
+
+    def bounding_sphere(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], float]: ...
+
+    def aabb(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[float]]: ...
+
+    def oriented_bounding_box(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[list[float]], list[float]]: ...
+
 
 class MeshObjectData:
     name: str
@@ -52,6 +112,7 @@ class MeshObjectData:
     texture_coordinates: list[AttributeData]
     color_sets: list[AttributeData]
     bone_influences: list[BoneInfluence]
+    influence_arrays: Optional[InfluenceArrays]
 
     def __init__(
         self,
@@ -68,9 +129,162 @@ class MeshObjectData:
         tangents: list[AttributeData] = [],
         texture_coordinates: list[AttributeData] = [],
         color_sets: list[AttributeData] = [],
-        bone_influences: list[BoneInfluence] = []
+        bone_influences: list[BoneInfluence] = [],
+        influence_arrays: Optional[InfluenceArrays] = None
+    ) -> None: ...
+
+    def weight_arrays(
+        self,
+        max_influences: Optional[int] = None,
+    ) -> tuple[numpy.ndarray, numpy.ndarray, list[str]]: ...
+
+    def weight_matrix(self) -> tuple[numpy.ndarray, list[str]]: ...
+
+    def set_weight_arrays(
+        self,
+        indices: numpy.ndarray,
+        weights: numpy.ndarray,
+        bone_names: list[str],
+    ) -> None: ...
+
+    def set_weight_matrix(
+        self,
+        weights: numpy.ndarray,
+        bone_names: list[str],
     ) -> None: ...
 
+    def normalize_weights(self) -> numpy.ndarray: ...
+
+    def prune_weights(self, threshold: float = 0.001) -> numpy.ndarray: ...
+
+    def limit_influences(self, max_influences: int = 4) -> numpy.ndarray: ...
+
+    def remove_empty_influences(self) -> list[str]: ...
+
+    def weld_vertices(self, tolerance: float = 1e-6) -> int: ...
+
+    def remove_unused_vertices(self) -> int: ...
+
+    def remove_degenerate_triangles(self) -> int: ...
+
+    def optimize_vertex_cache(self) -> None: ...
+
+    def optimize_overdraw(self, threshold: float = 1.05) -> None: ...
+
+    def optimize_vertex_fetch(self) -> None: ...
+
+    def simplify(
+        self,
+        target_triangle_count: int = 0,
+        target_error: float = 0.01,
+    ) -> int: ...
+
+    def recalculate_normals(
+        self,
+        angle_threshold: float = 180.0,
+        weighting: str = 'area',
+        preserve_split_normals: bool = False,
+    ) -> int: ...
+
+    def generate_tangent_space(self, uv_set: str = 'map1') -> int: ...
+
+    def flip_uv_v(self, uv_set: str = 'map1') -> None: ...
+
+    def transform_uvs(
+        self,
+        transform: UvTransform,
+        uv_set: str = 'map1',
+    ) -> None: ...
+
+    def copy_uv_set(self, uv_set: str, name: str) -> None: ...
+
+    def rename_uv_set(self, uv_set: str, name: str) -> None: ...
+
+    def uv_bounds(self, uv_set: str = 'map1') -> tuple[list[float], list[float]]: ...
+
+    def uv_island_count(self, uv_set: str = 'map1') -> int: ...
+
+    def color_set_display_values(
+        self,
+        name: str = 'colorSet1',
+        scale: Optional[float] = None,
+    ) -> numpy.ndarray: ...
+
+    def set_color_set_display_values(
+        self,
+        name: str,
+        values: numpy.ndarray,
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def fill_color_set(
+        self,
+        name: str,
+        color: list[float],
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def bake_ambient_occlusion(
+        self,
+        name: str = 'colorSet1',
+        channels: str = 'rgb',
+        sample_count: int = 64,
+        max_distance: Optional[float] = None,
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def color_set_u8(self, name: str) -> numpy.ndarray: ...
+
+    def set_color_set_u8(self, name: str, values: numpy.ndarray) -> None: ...
+
+    def flip_normals(
+        self,
+        flip_vectors: bool = True,
+        reverse_winding: bool = True,
+    ) -> None: ...
+
+    def mirror(
+        self,
+        axis: str = 'x',
+        rename_bones: bool = True,
+        skel: Optional[SkelData] = None,
+    ) -> MeshObjectData: ...
+
+    def add_attribute(
+        self,
+        name: AttributeName,
+        data: numpy.ndarray,
+    ) -> None: ...
+
+    def validate_attributes(self) -> None: ...
+
+    def split_by_connectivity(self) -> list[MeshObjectData]: ...
+
+    def split_by_triangle_ids(
+        self,
+        triangle_ids: numpy.ndarray,
+    ) -> list[MeshObjectData]: ...
+
+    def split_by_vertex_count(
+        self,
+        max_vertex_count: int,
+    ) -> list[MeshObjectData]: ...
+
+    def bounding_sphere(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], float]: ...
+
+    def aabb(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[float]]: ...
+
+    def oriented_bounding_box(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[list[float]], list[float]]: ...
+
 
 class AttributeData:
     name: str
@@ -94,6 +308,19 @@ class BoneInfluence:
     ) -> None: ...
 
 
+class InfluenceArrays:
+    indices: numpy.ndarray
+    weights: numpy.ndarray
+    bone_names: list[str]
+
+    def __init__(
+        self,
+        indices: numpy.ndarray,
+        weights: numpy.ndarray,
+        bone_names: list[str],
+    ) -> None: ...
+
+
 class VertexWeight:
     vertex_index: int
     vertex_weight: float
@@ -1,6 +1,6 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
@@ -17,6 +17,24 @@ class MeshExData:
     
     def save(self, path: str) -> None: ...
 
+    def update_from_mesh(self, mesh: MeshData) -> None: ...
+
+    def find_group(self, name: str) -> Optional[MeshObjectGroupData]: ...
+
+    def set_cast_shadow(
+        self,
+        name: str,
+        cast_shadow: bool,
+        subindex: Optional[int] = None,
+    ) -> None: ...
+
+    def set_draw_model(
+        self,
+        name: str,
+        draw_model: bool,
+        subindex: Optional[int] = None,
+    ) -> None: ...
+
 
 class MeshObjectGroupData:
     bounding_sphere: BoundingSphere
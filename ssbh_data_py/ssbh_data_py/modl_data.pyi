@@ -1,6 +1,6 @@
 # File automatically generated by build.rs.
 # Changes made to this file will not be saved.
-from typing import List, Tuple, Any, Optional, Union, ClassVar
+from typing import List, Tuple, Any, Optional, Union, ClassVar, Literal
 import numpy
 
 
@@ -25,6 +25,12 @@ class ModlData:
     
     def save(self, path: str) -> None: ...
 
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
 
 class ModlEntryData:
     mesh_object_name: str
@@ -42,3 +42,83 @@ fn vertex_adjacency_numpy() {
         "#})
     .unwrap();
 }
+
+#[test]
+fn adj_from_mesh() {
+    run_python_code(indoc! {r#"
+            o0 = ssbh_data_py.mesh_data.MeshObjectData("a", 0)
+            o0.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+            o0.positions = [ssbh_data_py.mesh_data.AttributeData("Position0", numpy.zeros((3, 3), dtype=numpy.float32))]
+
+            o1 = ssbh_data_py.mesh_data.MeshObjectData("b", 0)
+            o1.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+            o1.positions = [ssbh_data_py.mesh_data.AttributeData("Position0", numpy.zeros((3, 3), dtype=numpy.float32))]
+
+            mesh = ssbh_data_py.mesh_data.MeshData()
+            mesh.objects = [o0, o1]
+
+            adj = ssbh_data_py.adj_data.AdjData.from_mesh(mesh)
+            assert [e.mesh_object_index for e in adj.entries] == [0, 1]
+
+            adj = ssbh_data_py.adj_data.AdjData.from_mesh(mesh, [1])
+            assert [e.mesh_object_index for e in adj.entries] == [1]
+
+            assert adj.update(mesh, [0]) == [0]
+            assert [e.mesh_object_index for e in adj.entries] == [0, 1]
+
+            # Only the entries for the passed indices are recalculated.
+            before = adj.entries[1].vertex_adjacency.tolist()
+            o1.vertex_indices = numpy.array([0, 1, 2, 2, 1, 0], dtype=numpy.uint32)
+            adj.update(mesh, [0])
+            assert adj.entries[1].vertex_adjacency.tolist() == before
+
+            # Entries for removed objects are removed.
+            mesh.objects = [o0]
+            adj.update(mesh, [0])
+            assert [e.mesh_object_index for e in adj.entries] == [0]
+
+            # Only the entries for changed objects are replaced.
+            positions = numpy.array([[0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 1, 0]], dtype=numpy.float32)
+            o0.positions = [ssbh_data_py.mesh_data.AttributeData("Position0", positions)]
+            o0.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+            o1.positions = [ssbh_data_py.mesh_data.AttributeData("Position0", positions)]
+            o1.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+            mesh.objects = [o0, o1]
+            adj = ssbh_data_py.adj_data.AdjData.from_mesh(mesh)
+            assert adj.update(mesh) == []
+
+            before = adj.entries[0].vertex_adjacency.tolist()
+            o1.vertex_indices = numpy.array([0, 1, 2, 0, 2, 3], dtype=numpy.uint32)
+            mesh.objects = [o0, o1]
+            assert adj.update(mesh) == [1]
+            assert adj.entries[0].vertex_adjacency.tolist() == before
+            expected = ssbh_data_py.adj_data.AdjEntryData.from_mesh_object(1, o1)
+            assert adj.entries[1].vertex_adjacency.tolist() == expected.vertex_adjacency.tolist()
+
+            # Objects without an entry are added.
+            adj.entries = [adj.entries[1]]
+            assert adj.update(mesh) == [0]
+            assert [e.mesh_object_index for e in adj.entries] == [0, 1]
+
+            # Test exceptions.
+            mesh.objects = [ssbh_data_py.mesh_data.MeshObjectData("c", 0)]
+            try:
+                ssbh_data_py.adj_data.AdjData.from_mesh(mesh)
+                assert False
+            except ssbh_data_py.AdjDataError as e:
+                assert True
+
+            try:
+                ssbh_data_py.adj_data.AdjEntryData.from_mesh_object(0, mesh.objects[0])
+                assert False
+            except ssbh_data_py.AdjDataError as e:
+                assert True
+
+            try:
+                ssbh_data_py.adj_data.AdjData.from_mesh(mesh, [3])
+                assert False
+            except ssbh_data_py.AdjDataError as e:
+                assert True
+        "#})
+    .unwrap();
+}
@@ -251,3 +251,240 @@ fn create_track_values_rs_transform() {
         },
     );
 }
+
+#[test]
+fn blend_anims() {
+    run_python_code(indoc! {r#"
+            def create_anim(x):
+                t = ssbh_data_py.anim_data.Transform([1, 1, 1], [0, 0, 0, 1], [x, 0, 0])
+                track = ssbh_data_py.anim_data.TrackData('Transform', values=[t, t, t])
+                node = ssbh_data_py.anim_data.NodeData('A', [track])
+                a = ssbh_data_py.anim_data.AnimData()
+                a.groups = [ssbh_data_py.anim_data.GroupData(ssbh_data_py.anim_data.GroupType.Transform, [node])]
+                a.final_frame_index = 2.0
+                return a
+
+            a = ssbh_data_py.anim_data.blend_anims(create_anim(0.0), create_anim(10.0), [0.0, 0.5])
+            assert a.final_frame_index == 2.0
+            values = a.groups[0].nodes[0].tracks[0].values
+            assert [v.translation[0] for v in values] == [0.0, 5.0, 5.0]
+
+            a = ssbh_data_py.anim_data.apply_additive_anim(create_anim(1.0), create_anim(3.0))
+            values = a.groups[0].nodes[0].tracks[0].values
+            assert [v.translation[0] for v in values] == [1.0, 1.0, 1.0]
+
+            a = ssbh_data_py.anim_data.concatenate_anims([create_anim(0.0), create_anim(10.0)], crossfade_frames=1)
+            assert a.final_frame_index == 4.0
+            values = a.groups[0].nodes[0].tracks[0].values
+            assert [v.translation[0] for v in values] == [0.0, 0.0, 5.0, 10.0, 10.0]
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn concatenate_anims_empty() {
+    run_python_code(indoc! {r#"
+            try:
+                ssbh_data_py.anim_data.concatenate_anims([])
+                assert False
+            except ssbh_data_py.AnimDataError:
+                pass
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn reduce_track_data() {
+    run_python_code(indoc! {r#"
+            track = ssbh_data_py.anim_data.TrackData('a', values=[0.0, 1.0, 2.0, 2.0])
+            track.reduce()
+            assert track.values == [0.0, 1.0, 2.0, 2.0]
+
+            # Frames are replaced with interpolated values instead of being removed.
+            track = ssbh_data_py.anim_data.TrackData('a', values=[0.0, 1.0001, 2.0, 3.0])
+            track.reduce(tolerance=0.001)
+            assert len(track.values) == 4
+            assert abs(track.values[1] - 1.0) < 1e-5
+
+            track = ssbh_data_py.anim_data.TrackData('a', values=[1.0, 1.00001, 1.0])
+            track.reduce(tolerance=0.001)
+            assert track.values == [1.0]
+
+            track = ssbh_data_py.anim_data.TrackData('a', values=[True, False])
+            track.reduce(smoothing_window=3, smoothing_filter='gaussian')
+            assert track.values == [True, False]
+
+            try:
+                track.reduce(smoothing_filter='abc')
+                assert False
+            except ssbh_data_py.AnimDataError:
+                pass
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn reduce_anim_data() {
+    run_python_code(indoc! {r#"
+            track = ssbh_data_py.anim_data.TrackData('a', values=[0.5, 0.5, 0.5])
+            node = ssbh_data_py.anim_data.NodeData('A', [track])
+            a = ssbh_data_py.anim_data.AnimData()
+            a.groups = [ssbh_data_py.anim_data.GroupData(ssbh_data_py.anim_data.GroupType.Material, [node])]
+            a.reduce()
+            assert a.groups[0].nodes[0].tracks[0].values == [0.5]
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn evaluate_material_anim() {
+    run_python_code(indoc! {r#"
+            entry = ssbh_data_py.matl_data.MatlEntryData('a', 'b')
+            entry.floats = [ssbh_data_py.matl_data.FloatParam(ssbh_data_py.matl_data.ParamId.CustomFloat0, 0.0)]
+            matl = ssbh_data_py.matl_data.MatlData()
+            matl.entries = [entry]
+
+            tracks = [
+                ssbh_data_py.anim_data.TrackData('CustomFloat0', values=[0.0, 2.0]),
+                ssbh_data_py.anim_data.TrackData('CustomVector0', values=[[1.0, 2.0, 3.0, 4.0]]),
+            ]
+            anim = ssbh_data_py.anim_data.AnimData()
+            anim.groups = [
+                ssbh_data_py.anim_data.GroupData(ssbh_data_py.anim_data.GroupType.Material, [
+                    ssbh_data_py.anim_data.NodeData('a', tracks),
+                    ssbh_data_py.anim_data.NodeData('c', tracks),
+                ])
+            ]
+
+            result, missing = ssbh_data_py.anim_data.evaluate_material_anim(anim, matl, 0.5)
+            assert result.entries[0].floats[0].data == 1.0
+            assert matl.entries[0].floats[0].data == 0.0
+            assert len(missing) == 2
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn visible_mesh_objects() {
+    run_python_code(indoc! {r#"
+            mesh = ssbh_data_py.mesh_data.MeshData()
+            mesh.objects = [
+                ssbh_data_py.mesh_data.MeshObjectData('EyeL_VIS_O_OBJShape', 0),
+                ssbh_data_py.mesh_data.MeshObjectData('EyeL_VIS_O_OBJShape', 1),
+                ssbh_data_py.mesh_data.MeshObjectData('EyeR_VIS_O_OBJShape', 0),
+                ssbh_data_py.mesh_data.MeshObjectData('Body', 0),
+            ]
+
+            anim = ssbh_data_py.anim_data.AnimData()
+            anim.groups = [
+                ssbh_data_py.anim_data.create_visibility_group([{'EyeL'}, {'EyeR_VIS_O_OBJShape'}])
+            ]
+            assert [n.name for n in anim.groups[0].nodes] == ['EyeL', 'EyeR']
+            assert anim.groups[0].nodes[0].tracks[0].values == [True, False]
+
+            visible = ssbh_data_py.anim_data.visible_mesh_objects(anim, mesh, 0.0)
+            assert [(o.name, o.subindex) for o in visible] == [
+                ('EyeL_VIS_O_OBJShape', 0),
+                ('EyeL_VIS_O_OBJShape', 1),
+                ('Body', 0),
+            ]
+            assert visible[0] is mesh.objects[0]
+
+            # Visibility doesn't interpolate between frames.
+            visible = ssbh_data_py.anim_data.visible_mesh_objects(anim, mesh, 0.75)
+            assert [o.name for o in visible] == ['EyeL_VIS_O_OBJShape', 'EyeL_VIS_O_OBJShape', 'Body']
+
+            visible = ssbh_data_py.anim_data.visible_mesh_objects(anim, mesh, 1.0)
+            assert [o.name for o in visible] == ['EyeR_VIS_O_OBJShape', 'Body']
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn camera_values() {
+    run_python_code(indoc! {r#"
+            import numpy as np
+
+            camera = ssbh_data_py.anim_data.CameraValues(
+                np.array([[0.0, 0.0, 10.0], [10.0, 0.0, 0.0]], dtype=np.float32),
+                np.zeros((2, 3), dtype=np.float32),
+                np.array([[0.0, 1.0, 0.0], [0.0, 1.0, 0.0]], dtype=np.float32),
+                np.array([0.5, 0.6], dtype=np.float32),
+                np.array([1.0, 1.0], dtype=np.float32),
+                np.array([100.0, 100.0], dtype=np.float32),
+            )
+            anim = camera.to_anim()
+            assert anim.final_frame_index == 1.0
+            assert [n.name for n in anim.groups[0].nodes] == ['gya_camera', 'gya_cameraEye']
+            assert [t.name for t in anim.groups[1].nodes[0].tracks] == ['FarClip', 'FieldOfView', 'NearClip']
+
+            values = ssbh_data_py.anim_data.CameraValues.from_anim(anim)
+            assert np.allclose(values.position, camera.position)
+            assert np.allclose(values.target, camera.target)
+            assert np.allclose(values.up, camera.up, atol=1e-5)
+            assert np.allclose(values.field_of_view, camera.field_of_view)
+
+            try:
+                ssbh_data_py.anim_data.CameraValues.from_anim(ssbh_data_py.anim_data.AnimData())
+                assert False
+            except ssbh_data_py.AnimDataError:
+                pass
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn export_import_smd_anim() {
+    run_python_code(indoc! {r#"
+            import os
+            import tempfile
+
+            values = [ssbh_data_py.anim_data.Transform([1, 1, 1], [0, 0, 0, 1], [x, 0, 0]) for x in [0.0, 1.0, 2.0]]
+            track = ssbh_data_py.anim_data.TrackData('Transform', values=values)
+            node = ssbh_data_py.anim_data.NodeData('root', [track])
+            anim = ssbh_data_py.anim_data.AnimData()
+            anim.groups = [ssbh_data_py.anim_data.GroupData(ssbh_data_py.anim_data.GroupType.Transform, [node])]
+            anim.final_frame_index = 2.0
+
+            skel = ssbh_data_py.skel_data.SkelData()
+            skel.bones = [
+                ssbh_data_py.skel_data.BoneData('root', numpy.eye(4, dtype=numpy.float32), None),
+                ssbh_data_py.skel_data.BoneData('child', numpy.eye(4, dtype=numpy.float32), 0),
+            ]
+
+            path = os.path.join(tempfile.mkdtemp(), 'anim.smd')
+            ssbh_data_py.anim_data.export_smd_anim(anim, skel, path)
+
+            anim = ssbh_data_py.anim_data.import_smd_anim(path)
+            assert anim.final_frame_index == 2.0
+            assert [n.name for n in anim.groups[0].nodes] == ['root', 'child']
+            values = anim.groups[0].nodes[0].tracks[0].values
+            assert [v.translation[0] for v in values] == [0.0, 1.0, 2.0]
+        "#})
+    .unwrap();
+}
+
+#[test]
+fn anim_convert_version() {
+    run_python_code(indoc! {r#"
+        # Version 2.1 can't be saved.
+        anim = ssbh_data_py.anim_data.AnimData(2, 1)
+        anim.final_frame_index = 1.0
+        track = ssbh_data_py.anim_data.TrackData('Visibility', values=[True, False])
+        node = ssbh_data_py.anim_data.NodeData('a', [track])
+        anim.groups = [ssbh_data_py.anim_data.GroupData(ssbh_data_py.anim_data.GroupType.Visibility, [node])]
+
+        changes = anim.convert_version(2, 0)
+        assert changes == ['Data specific to anim version 2.1 will not be saved for version 2.0.']
+        assert (anim.major_version, anim.minor_version) == (2, 0)
+        assert anim.groups[0].nodes[0].tracks[0].values == [True, False]
+
+        try:
+            anim.convert_version(2, 1)
+            assert False
+        except ssbh_data_py.AnimDataError as e:
+            assert str(e) == 'Unsupported anim version 2.1. Expected one of 2.0.'
+        assert (anim.major_version, anim.minor_version) == (2, 0)
+    "#})
+    .unwrap();
+}
@@ -0,0 +1,115 @@
+use indoc::indoc;
+use ssbh_data_py::run_python_code;
+
+#[test]
+fn export_gltf() {
+    run_python_code(indoc! {r#"
+        import json
+        import os
+        import tempfile
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([[0, 0, 0], [1, 0, 0], [1, 1, 0]], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('root', [ssbh_data_py.mesh_data.VertexWeight(0, 1.0)])
+        ]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+
+        skel = ssbh_data_py.skel_data.SkelData()
+        skel.bones = [ssbh_data_py.skel_data.BoneData('root', numpy.eye(4, dtype=numpy.float32), None)]
+
+        modl = ssbh_data_py.modl_data.ModlData()
+        modl.entries = [ssbh_data_py.modl_data.ModlEntryData('a', 0, 'mat')]
+
+        entry = ssbh_data_py.matl_data.MatlEntryData('mat', 'shader')
+        entry.textures = [ssbh_data_py.matl_data.TextureParam(ssbh_data_py.matl_data.ParamId.Texture0, 'model_col')]
+        matl = ssbh_data_py.matl_data.MatlData()
+        matl.entries = [entry]
+
+        folder = tempfile.mkdtemp()
+        path = os.path.join(folder, 'model.gltf')
+        warnings = ssbh_data_py.gltf.export_gltf(path, modl, mesh, skel, matl)
+        assert os.path.exists(os.path.join(folder, 'model.bin'))
+
+        # Vertices 1 and 2 have no weights.
+        assert len(warnings) == 1
+        assert '2 vertices' in warnings[0]
+
+        with open(path) as f:
+            gltf = json.load(f)
+        assert [n['name'] for n in gltf['nodes']] == ['root', 'a']
+        assert gltf['nodes'][1]['skin'] == 0
+        assert gltf['images'] == [{'uri': 'model_col.png'}]
+
+        path = os.path.join(folder, 'model.glb')
+        ssbh_data_py.gltf.export_gltf(path, modl, mesh, skel, matl, None)
+        with open(path, 'rb') as f:
+            assert f.read(4) == b'glTF'
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn import_gltf() {
+    run_python_code(indoc! {r#"
+        import json
+        import os
+        import tempfile
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([[0, 0, 0], [1, 0, 0], [1, 1, 0]], dtype=numpy.float32))
+        ]
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.array([[0, 0], [1, 0], [1, 1]], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('root', [ssbh_data_py.mesh_data.VertexWeight(0, 1.0)])
+        ]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+
+        skel = ssbh_data_py.skel_data.SkelData()
+        skel.bones = [ssbh_data_py.skel_data.BoneData('root', numpy.eye(4, dtype=numpy.float32), None)]
+
+        modl = ssbh_data_py.modl_data.ModlData()
+        matl = ssbh_data_py.matl_data.MatlData()
+
+        path = os.path.join(tempfile.mkdtemp(), 'model.glb')
+        ssbh_data_py.gltf.export_gltf(path, modl, mesh, skel, matl)
+
+        mesh, skel, anim, dropped = ssbh_data_py.gltf.import_gltf(path)
+        assert dropped == []
+        assert [b.name for b in skel.bones] == ['root']
+        assert skel.bones[0].parent_index is None
+
+        o = mesh.objects[0]
+        assert o.name == 'a'
+        assert o.positions[0].name == 'Position0'
+        assert o.positions[0].data.tolist() == [[0, 0, 0], [1, 0, 0], [1, 1, 0]]
+        assert o.texture_coordinates[0].name == 'map1'
+        assert o.vertex_indices.tolist() == [0, 1, 2]
+        assert o.bone_influences[0].bone_name == 'root'
+        assert o.bone_influences[0].vertex_weights[0].vertex_index == 0
+        assert anim.groups == []
+
+        # Moving the joint without updating the inverse bind matrices changes the bind pose.
+        path = os.path.join(tempfile.mkdtemp(), 'model.gltf')
+        ssbh_data_py.gltf.export_gltf(path, modl, mesh, skel, matl)
+        with open(path) as f:
+            gltf = json.load(f)
+        gltf['nodes'][0]['translation'] = [0, 1, 0]
+        with open(path, 'w') as f:
+            json.dump(gltf, f)
+
+        mesh, skel, anim, dropped = ssbh_data_py.gltf.import_gltf(path)
+        assert len(dropped) == 1
+        assert 'Inverse bind matrices' in dropped[0]
+    "#})
+    .unwrap();
+}
@@ -77,3 +77,44 @@ fn cull_mode_enum_richcmp() {
     "#})
     .unwrap();
 }
+
+#[test]
+fn matl_convert_version() {
+    run_python_code(indoc! {r#"
+        # Common parameters are saved the same way for both versions.
+        m = ssbh_data_py.matl_data.MatlData(1, 5)
+        entry = ssbh_data_py.matl_data.MatlEntryData('a', 'SFX_PBS_0100000008008269_opaque')
+        entry.floats = [ssbh_data_py.matl_data.FloatParam(ssbh_data_py.matl_data.ParamId.CustomFloat0, 0.5)]
+        entry.booleans = [ssbh_data_py.matl_data.BooleanParam(ssbh_data_py.matl_data.ParamId.CustomBoolean1, True)]
+        entry.textures = [ssbh_data_py.matl_data.TextureParam(ssbh_data_py.matl_data.ParamId.Texture0, 'model_col')]
+        m.entries = [entry]
+        assert m.convert_version(1, 6) == []
+        assert (m.major_version, m.minor_version) == (1, 6)
+
+        # Version 1.5 doesn't store all of the state values from version 1.6.
+        blend_state = ssbh_data_py.matl_data.BlendStateData()
+        blend_state.alpha_sample_to_coverage = True
+        rasterizer_state = ssbh_data_py.matl_data.RasterizerStateData()
+        rasterizer_state.depth_bias = 1.0
+        entry.blend_states = [ssbh_data_py.matl_data.BlendStateParam(ssbh_data_py.matl_data.ParamId.BlendState0, blend_state)]
+        entry.rasterizer_states = [ssbh_data_py.matl_data.RasterizerStateParam(ssbh_data_py.matl_data.ParamId.RasterizerState0, rasterizer_state)]
+        m.entries = [entry]
+
+        changes = m.convert_version(1, 5)
+        assert len(changes) > 0
+        for change in changes:
+            assert change in [
+                'Parameter for material "a" BlendState0 will be changed.',
+                'Parameter for material "a" RasterizerState0 will be changed.',
+            ]
+        assert (m.major_version, m.minor_version) == (1, 5)
+
+        try:
+            m.convert_version(1, 7)
+            assert False
+        except ssbh_data_py.MatlDataError as e:
+            assert str(e) == 'Unsupported matl version 1.7. Expected one of 1.5, 1.6.'
+        assert (m.major_version, m.minor_version) == (1, 5)
+    "#})
+    .unwrap();
+}
@@ -212,3 +212,839 @@ fn calculate_tangents_vec4_ndarray() {
         ssbh_data_py.mesh_data.calculate_tangents_vec4(numpy.zeros((12,4), dtype=numpy.float32), numpy.zeros((12,4), dtype=numpy.float32), numpy.zeros((12,2), dtype=numpy.float32), numpy.arange(12, dtype=numpy.uint32))
     "#}).unwrap();
 }
+
+#[test]
+fn export_import_obj() {
+    run_python_code(indoc! {r#"
+        import os
+        import tempfile
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([[0, 0, 0], [1, 0, 0], [1, 1, 0]], dtype=numpy.float32))
+        ]
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.array([[0, 0], [1, 0], [1, 1]], dtype=numpy.float32))
+        ]
+        m = ssbh_data_py.mesh_data.MeshData()
+        m.objects = [o]
+
+        path = os.path.join(tempfile.mkdtemp(), 'model.obj')
+        ssbh_data_py.mesh_data.export_obj(m, path)
+
+        m = ssbh_data_py.mesh_data.import_obj(path)
+        assert len(m.objects) == 1
+        assert m.objects[0].name == 'a'
+        assert m.objects[0].vertex_indices.tolist() == [0, 1, 2]
+        assert m.objects[0].positions[0].data.tolist() == [[0, 0, 0], [1, 0, 0], [1, 1, 0]]
+        assert m.objects[0].texture_coordinates[0].data.tolist() == [[0, 0], [1, 0], [1, 1]]
+        assert m.objects[0].normals == []
+
+        # The v coordinate defaults to 0.
+        with open(path, 'w') as f:
+            f.write('v 0 0 0\nv 1 0 0\nv 1 1 0\nvt 0.5\nf 1/1 2/1 3/1\n')
+
+        m = ssbh_data_py.mesh_data.import_obj(path)
+        assert m.objects[0].texture_coordinates[0].data.tolist() == [[0.5, 1], [0.5, 1], [0.5, 1]]
+
+        try:
+            ssbh_data_py.mesh_data.import_obj('invalid')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn export_import_smd() {
+    run_python_code(indoc! {r#"
+        import os
+        import tempfile
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([[0, 0, 0], [1, 0, 0], [1, 1, 0]], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('root', [
+                ssbh_data_py.mesh_data.VertexWeight(0, 1.0),
+                ssbh_data_py.mesh_data.VertexWeight(1, 1.0),
+                ssbh_data_py.mesh_data.VertexWeight(2, 1.0)
+            ])
+        ]
+        m = ssbh_data_py.mesh_data.MeshData()
+        m.objects = [o]
+
+        s = ssbh_data_py.skel_data.SkelData()
+        s.bones = [ssbh_data_py.skel_data.BoneData('root', numpy.eye(4, dtype=numpy.float32), None)]
+
+        path = os.path.join(tempfile.mkdtemp(), 'model.smd')
+        ssbh_data_py.mesh_data.export_smd(m, s, path)
+
+        m, s = ssbh_data_py.mesh_data.import_smd(path)
+        assert [b.name for b in s.bones] == ['root']
+        assert len(m.objects) == 1
+        assert m.objects[0].name == 'a'
+        assert m.objects[0].vertex_indices.tolist() == [0, 1, 2]
+        assert m.objects[0].positions[0].data.tolist() == [[0, 0, 0], [1, 0, 0], [1, 1, 0]]
+        assert m.objects[0].bone_influences[0].bone_name == 'root'
+        assert len(m.objects[0].bone_influences[0].vertex_weights) == 3
+
+        # A root bone is added for skels without bones.
+        m.objects[0].bone_influences = []
+        ssbh_data_py.mesh_data.export_smd(m, ssbh_data_py.skel_data.SkelData(), path)
+
+        m, s = ssbh_data_py.mesh_data.import_smd(path)
+        assert [b.name for b in s.bones] == ['root']
+        assert m.objects[0].positions[0].data.tolist() == [[0, 0, 0], [1, 0, 0], [1, 1, 0]]
+
+        # Weights are normalized since SMD assigns the remaining weight to the parent bone.
+        s.bones = [s.bones[0], ssbh_data_py.skel_data.BoneData('a', numpy.eye(4, dtype=numpy.float32), 0)]
+        m.objects[0].bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('a', [
+                ssbh_data_py.mesh_data.VertexWeight(0, 0.25),
+                ssbh_data_py.mesh_data.VertexWeight(1, 0.25),
+                ssbh_data_py.mesh_data.VertexWeight(2, 0.25)
+            ])
+        ]
+        ssbh_data_py.mesh_data.export_smd(m, s, path)
+
+        m, s = ssbh_data_py.mesh_data.import_smd(path)
+        assert [i.bone_name for i in m.objects[0].bone_influences] == ['a']
+        assert [w.vertex_weight for w in m.objects[0].bone_influences[0].vertex_weights] == [1.0] * 3
+
+        def write_smd(vertex):
+            with open(path, 'w') as f:
+                f.write('version 1\nnodes\n0 "root" -1\n1 "a" 0\nend\nskeleton\ntime 0\n')
+                f.write('0 0 0 0 0 0 0\n1 0 0 0 0 0 0\nend\ntriangles\nmat\n')
+                f.write(vertex + '\n0 1 0 0 0 0 1 0 0\n0 1 1 0 0 0 1 0 0\nend\n')
+
+        # The remaining weight is assigned to the parent bone.
+        write_smd('0 0 0 0 0 0 1 0 0 1 1 0.25')
+        m, s = ssbh_data_py.mesh_data.import_smd(path)
+        weights = {i.bone_name: i.vertex_weights[0].vertex_weight for i in m.objects[0].bone_influences}
+        assert weights == {'root': 0.75, 'a': 0.25}
+
+        for vertex in [
+            '0 0 0 0 0 0 1 0 0 -1',
+            '0 0 0 0 0 0 1 0 0 nan',
+            '0 0 0 0 0 0 1 0 0 1.5 1 0.25',
+            '0 0 0 0 0 0 1 0 0 1e30 1 0.25',
+            '-1 0 0 0 0 0 1 0 0',
+        ]:
+            write_smd(vertex)
+            try:
+                ssbh_data_py.mesh_data.import_smd(path)
+                assert False
+            except ssbh_data_py.MeshDataError as e:
+                assert 'line 13' in str(e)
+
+        try:
+            ssbh_data_py.mesh_data.import_smd('invalid')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_weight_arrays() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.positions = [ssbh_data_py.mesh_data.AttributeData('Position0', numpy.zeros((3, 3), dtype=numpy.float32))]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('A', [
+                ssbh_data_py.mesh_data.VertexWeight(0, 0.25),
+                ssbh_data_py.mesh_data.VertexWeight(2, 1.0)
+            ]),
+            ssbh_data_py.mesh_data.BoneInfluence('B', [ssbh_data_py.mesh_data.VertexWeight(0, 0.75)]),
+        ]
+
+        indices, weights, names = o.weight_arrays()
+        assert indices.tolist() == [[1, 0], [0, 0], [0, 0]]
+        assert weights.tolist() == [[0.75, 0.25], [0.0, 0.0], [1.0, 0.0]]
+        assert names == ['A', 'B']
+
+        indices, weights, names = o.weight_arrays(max_influences=1)
+        assert indices.shape == (3, 1)
+
+        matrix, names = o.weight_matrix()
+        assert matrix.tolist() == [[0.25, 0.75], [0.0, 0.0], [1.0, 0.0]]
+
+        # Setting weights stores arrays instead of creating a VertexWeight for each weight.
+        o.set_weight_matrix(numpy.array([[0, 1], [1, 0], [0.5, 0.5]], dtype=numpy.float32), ['C', 'D'])
+        assert o.bone_influences == []
+        assert o.influence_arrays.bone_names == ['C', 'D']
+        matrix, names = o.weight_matrix()
+        assert matrix.tolist() == [[0, 1], [1, 0], [0.5, 0.5]]
+        assert names == ['C', 'D']
+
+        o.set_weight_arrays(
+            numpy.array([[1], [0], [1]], dtype=numpy.uint32),
+            numpy.array([[1], [1], [1]], dtype=numpy.float32),
+            ['C', 'D']
+        )
+        assert o.influence_arrays.indices.tolist() == [[1], [0], [1]]
+        matrix, names = o.weight_matrix()
+        assert matrix.tolist() == [[0, 1], [1, 0], [0, 1]]
+
+        # Editing weights keeps the arrays.
+        assert o.normalize_weights().tolist() == []
+        assert o.bone_influences == []
+        assert o.influence_arrays is not None
+
+        # The arrays are converted to influences when converting the mesh.
+        m = ssbh_data_py.mesh_data.MeshData()
+        m.objects = [o]
+        m.update_subindices()
+        assert [i.bone_name for i in m.objects[0].bone_influences] == ['C', 'D']
+        assert [w.vertex_index for w in m.objects[0].bone_influences[1].vertex_weights] == [0, 2]
+        assert m.objects[0].influence_arrays is None
+
+        try:
+            o.bone_influences = [ssbh_data_py.mesh_data.BoneInfluence('C', [])]
+            o.weight_matrix()
+            assert False
+        except ssbh_data_py.MeshDataError:
+            o.bone_influences = []
+
+        try:
+            o.set_weight_arrays(
+                numpy.array([[1], [0]], dtype=numpy.uint32),
+                numpy.array([[1], [1]], dtype=numpy.float32),
+                ['C', 'D']
+            )
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+
+        try:
+            ssbh_data_py.mesh_data.InfluenceArrays(
+                numpy.array([[2]], dtype=numpy.uint32),
+                numpy.array([[1]], dtype=numpy.float32),
+                ['C', 'D']
+            )
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+
+        try:
+            o.set_weight_matrix(numpy.zeros((3, 3), dtype=numpy.float32), ['C', 'D'])
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_weight_cleanup() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('A', [
+                ssbh_data_py.mesh_data.VertexWeight(0, 0.5),
+                ssbh_data_py.mesh_data.VertexWeight(1, 0.0001)
+            ]),
+            ssbh_data_py.mesh_data.BoneInfluence('B', [
+                ssbh_data_py.mesh_data.VertexWeight(0, 0.25),
+                ssbh_data_py.mesh_data.VertexWeight(1, 1.0)
+            ]),
+            ssbh_data_py.mesh_data.BoneInfluence('C', [ssbh_data_py.mesh_data.VertexWeight(0, 0.25)]),
+            ssbh_data_py.mesh_data.BoneInfluence('D', []),
+        ]
+
+        assert o.prune_weights().tolist() == [1]
+        assert o.limit_influences(max_influences=2).tolist() == [0]
+        assert o.normalize_weights().tolist() == [0]
+        assert o.remove_empty_influences() == ['D']
+
+        matrix, names = o.weight_matrix()
+        assert names == ['A', 'B', 'C']
+        assert numpy.allclose(matrix.sum(axis=1), [1.0, 1.0])
+        assert (matrix > 0).sum(axis=1).tolist() == [2, 1]
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_cleanup() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2, 3, 4, 5, 0, 0, 1], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 0, 0], [1, 0, 0], [1, 1, 0], [2, 2, 2]
+            ], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('A', [
+                ssbh_data_py.mesh_data.VertexWeight(2, 1.0),
+                ssbh_data_py.mesh_data.VertexWeight(5, 1.0),
+                ssbh_data_py.mesh_data.VertexWeight(6, 1.0)
+            ])
+        ]
+
+        assert o.weld_vertices() == 3
+        assert o.vertex_indices.tolist() == [0, 1, 2, 0, 1, 2, 0, 0, 1]
+        assert [w.vertex_index for w in o.bone_influences[0].vertex_weights] == [2, 3]
+
+        assert o.remove_unused_vertices() == 1
+        assert o.positions[0].data.tolist() == [[0, 0, 0], [1, 0, 0], [1, 1, 0]]
+        assert [w.vertex_index for w in o.bone_influences[0].vertex_weights] == [2]
+
+        assert o.remove_degenerate_triangles() == 1
+        assert o.vertex_indices.tolist() == [0, 1, 2, 0, 1, 2]
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_optimize() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([3, 2, 1, 2, 1, 0], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 1, 0], [5, 5, 5]
+            ], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('A', [
+                ssbh_data_py.mesh_data.VertexWeight(3, 1.0)
+            ])
+        ]
+
+        o.optimize_vertex_cache()
+        o.optimize_overdraw()
+        assert sorted(o.vertex_indices.tolist()) == [0, 1, 1, 2, 2, 3]
+
+        # Vertices are reordered by first use with unused vertices at the end.
+        o.optimize_vertex_fetch()
+        indices = o.vertex_indices.tolist()
+        assert indices[0] == 0
+        assert o.positions[0].data.tolist()[-1] == [5, 5, 5]
+        weight_index = o.bone_influences[0].vertex_weights[0].vertex_index
+        assert o.positions[0].data.tolist()[weight_index] == [0, 1, 0]
+
+        o.vertex_indices = numpy.array([0, 1], dtype=numpy.uint32)
+        try:
+            o.optimize_vertex_cache()
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_simplify() {
+    run_python_code(indoc! {r#"
+        # A flat 8x8 grid of quads.
+        n = 8
+        positions = [[x, y, 0] for y in range(n + 1) for x in range(n + 1)]
+        indices = []
+        for y in range(n):
+            for x in range(n):
+                i = y * (n + 1) + x
+                indices += [i, i + 1, i + n + 2, i, i + n + 2, i + n + 1]
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array(indices, dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array(positions, dtype=numpy.float32))
+        ]
+        o.normals = [
+            ssbh_data_py.mesh_data.AttributeData('Normal0', numpy.array([[0, i % 2, 1] for i in range(len(positions))], dtype=numpy.float32))
+        ]
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.array([[p[0] / n, p[1] / n] for p in positions], dtype=numpy.float32))
+        ]
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('A', [
+                ssbh_data_py.mesh_data.VertexWeight(i, p[0] / n) for i, p in enumerate(positions)
+            ]),
+            ssbh_data_py.mesh_data.BoneInfluence('B', [
+                ssbh_data_py.mesh_data.VertexWeight(i, 1.0 - p[0] / n) for i, p in enumerate(positions)
+            ]),
+        ]
+
+        assert o.simplify(target_triangle_count=64) >= 64
+        assert len(o.vertex_indices) <= 64 * 3
+        triangle_count = len(o.vertex_indices) // 3
+
+        # Remaining vertices blend the weights of the vertices collapsed onto them.
+        remaining = o.positions[0].data.tolist()
+        weights = [{} for _ in remaining]
+        for influence in o.bone_influences:
+            for w in influence.vertex_weights:
+                weights[w.vertex_index][influence.bone_name] = w.vertex_weight
+        assert all(abs(sum(w.values()) - 1.0) < 1e-5 for w in weights)
+        assert any(abs(w.get('A', 0.0) - p[0] / n) > 1e-4 for w, p in zip(weights, remaining))
+
+        # Normals and UVs are also blended.
+        normals = o.normals[0].data
+        assert numpy.allclose(numpy.linalg.norm(normals, axis=1), 1.0)
+        assert any(0.0 < v[1] < 1.0 for v in normals.tolist())
+        uvs = o.texture_coordinates[0].data.tolist()
+        assert any(abs(uv[0] - p[0] / n) > 1e-4 for uv, p in zip(uvs, remaining))
+
+        assert o.simplify() > 0
+        assert len(o.vertex_indices) // 3 < triangle_count
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_uvs() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2, 3, 4, 5], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0], [5, 5, 0], [6, 5, 0], [6, 6, 0]
+            ], dtype=numpy.float32))
+        ]
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.array([
+                [0, 0], [1, 0], [1, 1], [0, 0], [0.5, 0], [0.5, 2]
+            ], dtype=numpy.float32))
+        ]
+
+        assert o.uv_island_count() == 2
+        assert o.uv_bounds() == ([0.0, 0.0], [1.0, 2.0])
+
+        o.flip_uv_v()
+        assert o.texture_coordinates[0].data.tolist()[5] == [0.5, -1.0]
+
+        o.copy_uv_set('map1', 'bake1')
+        o.rename_uv_set('map1', 'uvSet')
+        assert [a.name for a in o.texture_coordinates] == ['uvSet', 'bake1']
+
+        transform = ssbh_data_py.matl_data.UvTransform(2.0, 3.0, 0.0, 1.0, 0.5)
+        o.transform_uvs(transform, 'bake1')
+        assert o.texture_coordinates[1].data.tolist()[0] == [1.0, 3.5]
+
+        try:
+            o.flip_uv_v('map1')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+
+        try:
+            o.rename_uv_set('uvSet', 'bake1')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_add_attribute() {
+    run_python_code(indoc! {r#"
+        names = ssbh_data_py.mesh_data.attribute_names()
+        assert names['positions'] == ['Position0']
+        assert 'bake1' in names['texture_coordinates']
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.add_attribute('Position0', numpy.zeros((3, 3), dtype=numpy.float32))
+        o.add_attribute('map1', numpy.zeros((3, 2), dtype=numpy.float32))
+        o.add_attribute('colorSet1', numpy.zeros((3, 4), dtype=numpy.float32))
+        assert o.positions[0].name == 'Position0'
+        assert o.texture_coordinates[0].name == 'map1'
+        assert o.color_sets[0].name == 'colorSet1'
+
+        # Existing attributes are replaced.
+        o.add_attribute('map1', numpy.ones((3, 2), dtype=numpy.float32))
+        assert len(o.texture_coordinates) == 1
+        assert o.texture_coordinates[0].data.tolist() == [[1, 1]] * 3
+        o.validate_attributes()
+
+        for name, data in [
+            ('position0', numpy.zeros((3, 3), dtype=numpy.float32)),
+            ('map1', numpy.zeros((3, 3), dtype=numpy.float32)),
+            ('bake1', numpy.zeros((4, 2), dtype=numpy.float32)),
+        ]:
+            try:
+                o.add_attribute(name, data)
+                assert False
+            except ssbh_data_py.MeshDataError as e:
+                assert '"a"' in str(e) and name in str(e)
+
+        # Arrays are converted to float32.
+        o.add_attribute('map1', numpy.ones((3, 2), dtype=numpy.float64))
+        assert o.texture_coordinates[0].data.dtype == numpy.float32
+        a = ssbh_data_py.mesh_data.AttributeData('map1', numpy.ones((3, 2), dtype=numpy.float64))
+        assert a.data.dtype == numpy.float32
+
+        for data, message in [
+            ('abc', 'does not have a 2D array of attribute values'),
+            (numpy.zeros((3, 5), dtype=numpy.float32), 'has 5 components but expected 2, 3, or 4'),
+        ]:
+            try:
+                o.add_attribute('map1', data)
+                assert False
+            except ssbh_data_py.MeshDataError as e:
+                assert str(e).startswith('Attribute "map1" for mesh object "a" ' + message)
+
+            try:
+                ssbh_data_py.mesh_data.AttributeData('map1', data)
+                assert False
+            except ssbh_data_py.MeshDataError as e:
+                assert str(e).startswith('Attribute "map1" ' + message)
+
+        # Assigning attribute lists is only checked by validate_attributes.
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('colorSet1', numpy.zeros((3, 2), dtype=numpy.float32))
+        ]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+        try:
+            mesh.validate_attributes()
+            assert False
+        except ssbh_data_py.MeshDataError as e:
+            assert 'colorSet1' in str(e)
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_color_sets() {
+    run_python_code(indoc! {r#"
+        assert ssbh_data_py.mesh_data.color_set_scale('colorSet1') == 2.0
+        assert ssbh_data_py.mesh_data.color_set_scale('colorSet5') is None
+        assert ssbh_data_py.mesh_data.color_set_scale('colorSet9') is None
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0]
+            ], dtype=numpy.float32))
+        ]
+
+        # colorSet1 is stored as half the displayed value.
+        o.fill_color_set('colorSet1', [1.0, 1.0, 1.0, 1.0])
+        assert o.color_sets[0].data.tolist() == [[0.5, 0.5, 0.5, 0.5]] * 3
+        assert o.color_set_display_values('colorSet1').tolist() == [[1.0, 1.0, 1.0, 1.0]] * 3
+
+        o.set_color_set_display_values('colorSet1', numpy.array([[0.5, 0.5, 0.5, 1.0]] * 3, dtype=numpy.float32), scale=1.0)
+        assert o.color_sets[0].data.tolist() == [[0.5, 0.5, 0.5, 1.0]] * 3
+
+        assert o.color_set_u8('colorSet1').tolist() == [[128, 128, 128, 255]] * 3
+        o.set_color_set_u8('colorSet1', numpy.array([[0, 51, 255, 255]] * 3, dtype=numpy.uint8))
+        assert o.color_set_u8('colorSet1').tolist() == [[0, 51, 255, 255]] * 3
+
+        # A single triangle doesn't occlude itself.
+        o.bake_ambient_occlusion('colorSet1', channels='a')
+        assert o.color_set_display_values('colorSet1').tolist()[0][3] == 1.0
+
+        # Color sets without a known scale require specifying the scale.
+        o.fill_color_set('colorSet5', [1.0, 1.0, 1.0, 1.0], scale=1.0)
+        assert o.color_set_display_values('colorSet5', scale=1.0).tolist() == [[1.0, 1.0, 1.0, 1.0]] * 3
+
+        for f in [
+            lambda: o.fill_color_set('colorSet9', [1.0, 1.0, 1.0, 1.0]),
+            lambda: o.fill_color_set('colorSet5', [1.0, 1.0, 1.0, 1.0]),
+            lambda: o.color_set_display_values('colorSet5'),
+            lambda: o.color_set_display_values('colorSet2'),
+            lambda: o.bake_ambient_occlusion(channels='xyz'),
+        ]:
+            try:
+                f()
+                assert False
+            except ssbh_data_py.MeshDataError:
+                pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_split_merge() {
+    run_python_code(indoc! {r#"
+        a = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        a.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        a.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0]
+            ], dtype=numpy.float32))
+        ]
+        b = ssbh_data_py.mesh_data.MeshObjectData('b', 0, parent_bone_name='B')
+        b.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        b.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 1], [1, 0, 1], [1, 1, 1]
+            ], dtype=numpy.float32))
+        ]
+
+        try:
+            ssbh_data_py.mesh_data.merge_mesh_objects([a, b])
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+
+        skel = ssbh_data_py.skel_data.SkelData()
+        skel.bones = [ssbh_data_py.skel_data.BoneData('B', numpy.eye(4, dtype=numpy.float32), None)]
+        merged = ssbh_data_py.mesh_data.merge_mesh_objects([a, b], skel)
+        assert merged.name == 'a'
+        assert merged.parent_bone_name == ''
+        assert merged.vertex_indices.tolist() == [0, 1, 2, 3, 4, 5]
+        assert merged.bone_influences[0].bone_name == 'B'
+        assert [w.vertex_index for w in merged.bone_influences[0].vertex_weights] == [3, 4, 5]
+
+        parts = merged.split_by_connectivity()
+        assert len(parts) == 2
+        assert parts[1].positions[0].data.tolist() == [[0, 0, 1], [1, 0, 1], [1, 1, 1]]
+
+        parts = merged.split_by_triangle_ids(numpy.array([1, 0], dtype=numpy.uint32))
+        assert parts[0].positions[0].data.tolist() == [[0, 0, 1], [1, 0, 1], [1, 1, 1]]
+
+        assert len(merged.split_by_vertex_count(3)) == 2
+
+        # Split objects keep the subindex until the subindices are updated.
+        assert [p.subindex for p in parts] == [merged.subindex, merged.subindex]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = parts
+        mesh.update_subindices()
+        assert [o.subindex for o in mesh.objects] == [0, 1]
+
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        c = ssbh_data_py.mesh_data.MeshObjectData('a', 1)
+        mesh.objects = [a, c, a]
+        modl = ssbh_data_py.modl_data.ModlData()
+        modl.entries = [
+            ssbh_data_py.modl_data.ModlEntryData('a', 1, 'C'),
+            ssbh_data_py.modl_data.ModlEntryData('b', 0, 'B'),
+        ]
+        mesh.update_subindices(modl)
+        assert [o.subindex for o in mesh.objects] == [0, 1, 2]
+        assert [(e.mesh_object_name, e.mesh_object_subindex, e.material_label) for e in modl.entries] == [
+            ('a', 1, 'C'),
+            ('b', 0, 'B'),
+        ]
+
+        # Objects with the same name and subindex can't be matched to a single entry.
+        mesh.objects = [
+            ssbh_data_py.mesh_data.MeshObjectData('a', 0),
+            ssbh_data_py.mesh_data.MeshObjectData('a', 0),
+        ]
+        modl.entries = [ssbh_data_py.modl_data.ModlEntryData('a', 0, 'A')]
+        try:
+            mesh.update_subindices(modl)
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+        assert [o.subindex for o in mesh.objects] == [0, 0]
+        assert [e.material_label for e in modl.entries] == ['A']
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_mirror() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0, parent_bone_name='ArmL')
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0]
+            ], dtype=numpy.float32))
+        ]
+        o.tangents = [
+            ssbh_data_py.mesh_data.AttributeData('Tangent0', numpy.array([
+                [1, 0, 0, 1], [1, 0, 0, 1], [1, 0, 0, 1]
+            ], dtype=numpy.float32))
+        ]
+        o.bone_influences = [ssbh_data_py.mesh_data.BoneInfluence('FingerL11', [])]
+
+        mirrored = o.mirror()
+        assert mirrored.vertex_indices.tolist() == [0, 2, 1]
+        assert mirrored.positions[0].data.tolist() == [[0, 0, 0], [-1, 0, 0], [-1, 1, 0]]
+        assert mirrored.tangents[0].data.tolist() == [[-1, 0, 0, -1], [-1, 0, 0, -1], [-1, 0, 0, -1]]
+        assert mirrored.parent_bone_name == 'ArmR'
+        assert mirrored.bone_influences[0].bone_name == 'FingerR11'
+        assert o.parent_bone_name == 'ArmL'
+
+        skel = ssbh_data_py.skel_data.SkelData()
+        skel.bones = [ssbh_data_py.skel_data.BoneData('ArmR', numpy.eye(4, dtype=numpy.float32), None)]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+        mirrored = mesh.mirror('x', skel=skel)
+        assert mirrored.objects[0].parent_bone_name == 'ArmR'
+        assert mirrored.objects[0].bone_influences[0].bone_name == 'FingerL11'
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_normals() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2, 0, 2, 3, 0, 3, 5, 0, 5, 4], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0], [0, 1, 0], [0, 0, -1], [0, 1, -1]
+            ], dtype=numpy.float32))
+        ]
+
+        assert o.recalculate_normals(angle_threshold=30.0, weighting='angle') == 2
+        assert o.vertex_indices.tolist() == [0, 1, 2, 0, 2, 3, 6, 7, 5, 6, 5, 4]
+        assert o.positions[0].data.shape == (8, 3)
+        assert o.normals[0].name == 'Normal0'
+        assert o.normals[0].data[0].tolist() == [0, 0, 1, 0]
+        assert o.normals[0].data[6].tolist() == [-1, 0, 0, 0]
+
+        o.flip_normals()
+        assert o.vertex_indices.tolist()[:3] == [0, 2, 1]
+        assert o.normals[0].data[0].tolist() == [0, 0, -1, 0]
+
+        # Neighboring faces are 14 degrees apart, but the faces at the ends are 54 degrees apart.
+        angles = numpy.radians(numpy.arange(6) * 20.0)
+        fan = ssbh_data_py.mesh_data.MeshObjectData('fan', 0)
+        fan.vertex_indices = numpy.array([[0, i + 1, i + 2] for i in range(5)], dtype=numpy.uint32).flatten()
+        fan.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array(
+                [[0, 0, 1]] + [[numpy.cos(a), numpy.sin(a), 0] for a in angles], dtype=numpy.float32
+            ))
+        ]
+        assert fan.recalculate_normals(angle_threshold=30.0) == 0
+
+        # Repeated vertex indices don't produce NaN.
+        fan.vertex_indices = numpy.array([0, 1, 2, 0, 0, 1], dtype=numpy.uint32)
+        assert fan.recalculate_normals(weighting='angle') == 0
+        assert numpy.isfinite(fan.normals[0].data).all()
+
+        try:
+            o.recalculate_normals(weighting='cotangent')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_object_generate_tangent_space() {
+    run_python_code(indoc! {r#"
+        import warnings
+
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.vertex_indices = numpy.array([0, 1, 2], dtype=numpy.uint32)
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [1, 0, 0], [1, 1, 0]
+            ], dtype=numpy.float32))
+        ]
+        o.normals = [
+            ssbh_data_py.mesh_data.AttributeData('Normal0', numpy.array([
+                [0, 0, 1, 0], [0, 0, 1, 0], [0, 0, 1, 0]
+            ], dtype=numpy.float32))
+        ]
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.array([
+                [0, 0], [1, 0], [1, 1]
+            ], dtype=numpy.float32))
+        ]
+
+        assert o.generate_tangent_space() == 0
+        assert o.tangents[0].name == 'Tangent0'
+        assert o.binormals[0].name == 'Binormal0'
+        assert numpy.allclose(o.tangents[0].data[0], [1, 0, 0, 1])
+        assert numpy.allclose(o.binormals[0].data[0], [0, 1, 0])
+
+        o.texture_coordinates[0].data = numpy.zeros((3, 2), dtype=numpy.float32)
+        with warnings.catch_warnings(record=True) as w:
+            warnings.simplefilter('always')
+            o.generate_tangent_space('map1')
+            assert len(w) == 1
+
+        try:
+            o.generate_tangent_space('bake1')
+            assert False
+        except ssbh_data_py.MeshDataError:
+            pass
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_bounding_volumes() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0, parent_bone_name='B')
+        o.positions = [
+            ssbh_data_py.mesh_data.AttributeData('Position0', numpy.array([
+                [0, 0, 0], [2, 0, 0], [1, 2, 0]
+            ], dtype=numpy.float32))
+        ]
+
+        center, radius = o.bounding_sphere()
+        assert numpy.allclose(center, [1, 2/3, 0])
+        assert numpy.isclose(radius, 4/3)
+        assert o.aabb() == ([0, 0, 0], [2, 2, 0])
+        center, transform, size = o.oriented_bounding_box()
+        assert center == [1, 1, 0]
+        assert transform == [[1, 0, 0], [0, 1, 0], [0, 0, 1]]
+        assert size == [1, 1, 0]
+
+        skel = ssbh_data_py.skel_data.SkelData()
+        transform = numpy.eye(4, dtype=numpy.float32)
+        transform[3, 2] = 1
+        skel.bones = [ssbh_data_py.skel_data.BoneData('B', transform, None)]
+        assert o.aabb(skel) == ([0, 0, 1], [2, 2, 1])
+
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+        assert mesh.aabb() == ([0, 0, 0], [2, 2, 0])
+        assert mesh.aabb(skel=skel) == ([0, 0, 1], [2, 2, 1])
+
+        # Skinned objects ignore the skel.
+        o.bone_influences = [
+            ssbh_data_py.mesh_data.BoneInfluence('B', [ssbh_data_py.mesh_data.VertexWeight(0, 1.0)])
+        ]
+        assert o.aabb(skel) == ([0, 0, 0], [2, 2, 0])
+
+        # The oriented box matches the axis-aligned box like ssbh_data even for rotated points.
+        o.positions[0].data = numpy.array([[0, 0, 0], [1, 1, 0], [2, 2, 0]], dtype=numpy.float32)
+        center, transform, size = o.oriented_bounding_box()
+        assert center == [1, 1, 0]
+        assert transform == [[1, 0, 0], [0, 1, 0], [0, 0, 1]]
+        assert size == [1, 1, 0]
+    "#})
+    .unwrap();
+}
+
+#[test]
+fn mesh_convert_version() {
+    run_python_code(indoc! {r#"
+        o = ssbh_data_py.mesh_data.MeshObjectData('a', 0)
+        o.add_attribute('Position0', numpy.zeros((3, 3), dtype=numpy.float32))
+        o.texture_coordinates = [
+            ssbh_data_py.mesh_data.AttributeData('map1', numpy.zeros((3, 2), dtype=numpy.float32)),
+            ssbh_data_py.mesh_data.AttributeData('uvCustom', numpy.zeros((3, 2), dtype=numpy.float32)),
+        ]
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [o]
+
+        assert mesh.convert_version(1, 10) == []
+        assert mesh.convert_version(1, 9) == []
+        assert (mesh.major_version, mesh.minor_version) == (1, 9)
+
+        # Version 1.8 doesn't store attribute names.
+        changes = mesh.convert_version(1, 8)
+        assert any('"a"' in c and 'uvCustom' in c for c in changes)
+        assert (mesh.major_version, mesh.minor_version) == (1, 8)
+
+        try:
+            mesh.convert_version(2, 0)
+            assert False
+        except ssbh_data_py.MeshDataError as e:
+            assert '1.8, 1.9, 1.10' in str(e)
+        assert (mesh.major_version, mesh.minor_version) == (1, 8)
+    "#})
+    .unwrap();
+}
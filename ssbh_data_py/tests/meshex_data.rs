@@ -47,3 +47,37 @@ fn create_meshex_from_objects() {
     "#})
     .unwrap();
 }
+
+#[test]
+fn update_meshex_from_mesh() {
+    run_python_code(indoc! {r#"
+        mesh = ssbh_data_py.mesh_data.MeshData()
+        mesh.objects = [
+            ssbh_data_py.mesh_data.MeshObjectData('a', 0),
+            ssbh_data_py.mesh_data.MeshObjectData('b', 0)
+        ]
+        m = ssbh_data_py.meshex_data.MeshExData.from_mesh_objects(mesh.objects)
+        m.set_cast_shadow('a', False)
+        assert all(not f.cast_shadow for f in m.find_group('a').entry_flags)
+        assert m.find_group('d') is None
+
+        m.find_group('b').entry_flags[0].draw_model = False
+        assert not m.find_group('b').entry_flags[0].draw_model
+
+        mesh.objects = [
+            ssbh_data_py.mesh_data.MeshObjectData('a', 0),
+            ssbh_data_py.mesh_data.MeshObjectData('c', 0)
+        ]
+        m.update_from_mesh(mesh)
+        assert [g.mesh_object_full_name for g in m.mesh_object_groups] == ['a', 'c']
+        assert all(not f.cast_shadow for f in m.find_group('a').entry_flags)
+        assert all(f.cast_shadow for f in m.find_group('c').entry_flags)
+
+        try:
+            m.set_draw_model('d', False)
+            assert False
+        except ssbh_data_py.MeshExDataError:
+            pass
+    "#})
+    .unwrap();
+}
@@ -47,3 +47,29 @@ fn create_modl_entry() {
     "#})
     .unwrap();
 }
+
+#[test]
+fn modl_convert_version() {
+    run_python_code(indoc! {r#"
+        m = ssbh_data_py.modl_data.ModlData(1, 6)
+        m.model_name = 'model'
+        m.entries = [
+            ssbh_data_py.modl_data.ModlEntryData('a', 0, 'A'),
+            ssbh_data_py.modl_data.ModlEntryData('a', 1, 'B'),
+        ]
+
+        # The entries are unchanged when saving the only supported version.
+        changes = m.convert_version(1, 7)
+        assert changes == ['Data specific to modl version 1.6 will not be saved for version 1.7.']
+        assert (m.major_version, m.minor_version) == (1, 7)
+        assert m.convert_version(1, 7) == []
+
+        try:
+            m.convert_version(1, 6)
+            assert False
+        except ssbh_data_py.ModlDataError as e:
+            assert str(e) == 'Unsupported modl version 1.6. Expected one of 1.7.'
+        assert (m.major_version, m.minor_version) == (1, 7)
+    "#})
+    .unwrap();
+}
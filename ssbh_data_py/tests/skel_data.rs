@@ -157,3 +157,26 @@ fn calculate_relative_transform_no_parent_tuple() {
     "#})
     .unwrap();
 }
+
+#[test]
+fn skel_convert_version() {
+    run_python_code(indoc! {r#"
+        s = ssbh_data_py.skel_data.SkelData(1, 1)
+        s.bones = [
+            ssbh_data_py.skel_data.BoneData('a', numpy.eye(4, dtype=numpy.float32), None),
+            ssbh_data_py.skel_data.BoneData('b', numpy.eye(4, dtype=numpy.float32), 0),
+        ]
+
+        changes = s.convert_version(1, 0)
+        assert changes == ['Data specific to skel version 1.1 will not be saved for version 1.0.']
+        assert (s.major_version, s.minor_version) == (1, 0)
+
+        try:
+            s.convert_version(2, 0)
+            assert False
+        except ssbh_data_py.SkelDataError as e:
+            assert str(e) == 'Unsupported skel version 2.0. Expected one of 1.0.'
+        assert (s.major_version, s.minor_version) == (1, 0)
+    "#})
+    .unwrap();
+}
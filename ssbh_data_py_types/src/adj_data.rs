@@ -7,7 +7,7 @@ create_exception!(ssbh_data_py, AdjDataError, pyo3::exceptions::PyException);
 pub mod adj_data {
     pub use super::*;
 
-    use crate::{mesh_data::map_into_vector_data, PyRepr, Pyi, PyiMethods};
+    use crate::{PyRepr, Pyi, PyiMethods};
     use map_py::{MapPy, TypedList};
     use numpy::PyArray1;
 
@@ -36,6 +36,35 @@ pub mod adj_data {
                 .map_err(|e| AdjDataError::new_err(format!("{e}")))
         }
 
+        #[staticmethod]
+        #[pyo3(signature = (mesh, object_indices = None))]
+        fn from_mesh(
+            py: Python,
+            mesh: crate::mesh_data::mesh_data::MeshData,
+            object_indices: Option<Vec<usize>>,
+        ) -> PyResult<Self> {
+            let object_count = mesh.objects.list.bind(py).len();
+            let object_indices = object_indices.unwrap_or_else(|| (0..object_count).collect());
+            let entries = mesh_adj_entries(py, &mesh, &object_indices)?;
+            ssbh_data::adj_data::AdjData { entries }.map_py(py)
+        }
+
+        #[pyo3(signature = (mesh, object_indices = None))]
+        fn update(
+            &mut self,
+            py: Python,
+            mesh: crate::mesh_data::mesh_data::MeshData,
+            object_indices: Option<Vec<usize>>,
+        ) -> PyResult<Vec<usize>> {
+            let object_count = mesh.objects.list.bind(py).len();
+            let object_indices = object_indices.unwrap_or_else(|| (0..object_count).collect());
+            let regenerated = mesh_adj_entries(py, &mesh, &object_indices)?;
+            let mut adj = self.clone().map_py(py)?;
+            let updated = crate::adj_update::update_adj(&mut adj, object_count, regenerated);
+            *self = adj.map_py(py)?;
+            Ok(updated)
+        }
+
         fn __repr__(&self) -> String {
             self.py_repr()
         }
@@ -43,9 +72,21 @@ pub mod adj_data {
 
     impl PyiMethods for AdjData {
         fn pyi_methods() -> String {
-            "    def __init__(self,) -> None: ...
-        
-        def save(self, path: str) -> None: ..."
+            "    def __init__(self) -> None: ...
+
+    def save(self, path: str) -> None: ...
+
+    @staticmethod
+    def from_mesh(
+        mesh: MeshData,
+        object_indices: Optional[list[int]] = None,
+    ) -> AdjData: ...
+
+    def update(
+        self,
+        mesh: MeshData,
+        object_indices: Optional[list[int]] = None,
+    ) -> list[int]: ..."
                 .to_string()
         }
     }
@@ -76,17 +117,7 @@ pub mod adj_data {
             mesh_object_index: usize,
             mesh_object: &crate::mesh_data::mesh_data::MeshObjectData,
         ) -> PyResult<Self> {
-            let vertex_indices: Vec<u32> = mesh_object.vertex_indices.extract(py)?;
-            let positions: Vec<crate::mesh_data::mesh_data::AttributeData> =
-                mesh_object.positions.list.extract(py)?;
-            // TODO: Avoid unwrap?
-            let vertex_positions = map_into_vector_data(positions[0].data.clone(), py)?;
-            let entry = ssbh_data::adj_data::AdjEntryData::from_vector_data(
-                mesh_object_index,
-                &vertex_positions,
-                &vertex_indices,
-            );
-            entry.map_py(py)
+            adj_entry(py, mesh_object_index, mesh_object)?.map_py(py)
         }
 
         fn __repr__(&self) -> String {
@@ -97,17 +128,65 @@ pub mod adj_data {
     impl PyiMethods for AdjEntryData {
         fn pyi_methods() -> String {
             "    def __init__(
-            self,
-            mesh_object_index: int
-        ) -> None: ...
-        
-        @staticmethod
-        def from_mesh_object(mesh_object_index: int,
-                             mesh_object: MeshObjectData) -> AdjEntryData: ..."
+        self,
+        mesh_object_index: int,
+    ) -> None: ...
+
+    @staticmethod
+    def from_mesh_object(
+        mesh_object_index: int,
+        mesh_object: MeshObjectData,
+    ) -> AdjEntryData: ..."
                 .to_string()
         }
     }
 
+    // Only convert the data used for adjacency instead of the entire mesh.
+    fn mesh_adj_entries(
+        py: Python,
+        mesh: &crate::mesh_data::mesh_data::MeshData,
+        object_indices: &[usize],
+    ) -> PyResult<Vec<ssbh_data::adj_data::AdjEntryData>> {
+        let objects = mesh.objects.list.bind(py);
+        crate::adj_update::object_indices(object_indices, objects.len())
+            .map_err(AdjDataError::new_err)?
+            .into_iter()
+            .map(|i| {
+                let object = objects.get_item(i)?;
+                let object = object
+                    .downcast::<crate::mesh_data::mesh_data::MeshObjectData>()?
+                    .borrow();
+                adj_entry(py, i, &object)
+            })
+            .collect()
+    }
+
+    fn adj_entry(
+        py: Python,
+        mesh_object_index: usize,
+        object: &crate::mesh_data::mesh_data::MeshObjectData,
+    ) -> PyResult<ssbh_data::adj_data::AdjEntryData> {
+        let positions = match object.positions.list.bind(py).iter().next() {
+            Some(p) => {
+                let data = p
+                    .downcast::<crate::mesh_data::mesh_data::AttributeData>()?
+                    .borrow()
+                    .data
+                    .clone_ref(py);
+                Some(crate::mesh_data::map_into_vector_data(data, py)?)
+            }
+            None => None,
+        };
+        let vertex_indices: Vec<u32> = object.vertex_indices.extract(py)?;
+        crate::adj_update::adj_entry(
+            mesh_object_index,
+            &object.name,
+            positions.as_ref(),
+            &vertex_indices,
+        )
+        .map_err(AdjDataError::new_err)
+    }
+
     #[pyfunction]
     fn read_adj(py: Python, path: &str) -> PyResult<AdjData> {
         ssbh_data::adj_data::AdjData::from_file(path)
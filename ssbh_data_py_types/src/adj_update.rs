@@ -0,0 +1,72 @@
+use ssbh_data::adj_data::{AdjData, AdjEntryData};
+use ssbh_data::mesh_data::VectorData;
+
+/// Sorts `object_indices` and removes duplicates.
+/// Each index must be less than `object_count`.
+pub fn object_indices(object_indices: &[usize], object_count: usize) -> Result<Vec<usize>, String> {
+    let mut object_indices = object_indices.to_vec();
+    object_indices.sort_unstable();
+    object_indices.dedup();
+
+    match object_indices.iter().find(|i| **i >= object_count) {
+        Some(i) => Err(format!(
+            "Mesh object index {i} is out of range for a mesh with {object_count} objects."
+        )),
+        None => Ok(object_indices),
+    }
+}
+
+/// Calculates the adjacency for the mesh object `name` at `mesh_object_index`
+/// from its first position attribute and vertex indices.
+pub fn adj_entry(
+    mesh_object_index: usize,
+    name: &str,
+    positions: Option<&VectorData>,
+    vertex_indices: &[u32],
+) -> Result<AdjEntryData, String> {
+    let positions = positions.ok_or_else(|| {
+        format!("Mesh object {name:?} at index {mesh_object_index} has no positions.")
+    })?;
+    Ok(AdjEntryData::from_vector_data(
+        mesh_object_index,
+        positions,
+        vertex_indices,
+    ))
+}
+
+/// Replaces the entries in `adj` that differ from the `regenerated` entries for the same object indices
+/// and returns the object indices of the replaced entries in order.
+///
+/// An entry is replaced if its object has no entry yet or if its adjacency changed
+/// from editing the vertex count, vertex indices, or positions of the object.
+/// Entries whose object index is not less than `object_count` are removed.
+/// Entries are sorted by object index.
+pub fn update_adj(
+    adj: &mut AdjData,
+    object_count: usize,
+    regenerated: Vec<AdjEntryData>,
+) -> Vec<usize> {
+    adj.entries.retain(|e| e.mesh_object_index < object_count);
+
+    let mut updated = Vec::new();
+    for entry in regenerated {
+        match adj
+            .entries
+            .iter_mut()
+            .find(|e| e.mesh_object_index == entry.mesh_object_index)
+        {
+            Some(e) if e.vertex_adjacency == entry.vertex_adjacency => (),
+            Some(e) => {
+                updated.push(entry.mesh_object_index);
+                *e = entry;
+            }
+            None => {
+                updated.push(entry.mesh_object_index);
+                adj.entries.push(entry);
+            }
+        }
+    }
+    adj.entries.sort_by_key(|e| e.mesh_object_index);
+    updated.sort_unstable();
+    updated
+}
@@ -0,0 +1,218 @@
+use glam::{Quat, Vec3};
+use ssbh_data::anim_data::{AnimData, GroupType, TrackValues};
+
+use crate::anim_sampling::{
+    anim_frame_count, collect_track_values, combined_groups, find_track, lerp_value, quat,
+    sample_track, track_frame_count, transform, vec3, TrackValue,
+};
+
+/// Blends the tracks in `a` and `b` using the per frame weights in `weights`.
+/// A weight of `0.0` uses `a` and a weight of `1.0` uses `b`.
+/// The last weight is used for any remaining frames.
+/// Tracks present in only one anim are copied unchanged.
+pub fn blend_anims(a: &AnimData, b: &AnimData, weights: &[f32]) -> AnimData {
+    let frame_count = anim_frame_count(a).max(anim_frame_count(b));
+
+    let mut groups = combined_groups(&[a, b]);
+    for group in &mut groups {
+        for node in &mut group.nodes {
+            for track in &mut node.tracks {
+                let track_a = find_track(a, &group.group_type, &node.name, &track.name);
+                let track_b = find_track(b, &group.group_type, &node.name, &track.name);
+                if let (Some(track_a), Some(track_b)) = (track_a, track_b) {
+                    let is_constant = track_frame_count(&track_a.values) <= 1
+                        && track_frame_count(&track_b.values) <= 1
+                        && weights.len() <= 1;
+                    let count = if is_constant { 1 } else { frame_count };
+
+                    let values = (0..count).filter_map(|frame| {
+                        let weight = weights
+                            .get(frame)
+                            .or(weights.last())
+                            .copied()
+                            .unwrap_or(0.5);
+                        let value_a = sample_track(&track_a.values, frame as f32)?;
+                        let value_b = sample_track(&track_b.values, frame as f32)?;
+                        Some(lerp_value(&value_a, &value_b, weight))
+                    });
+                    if let Some(values) = collect_track_values(values.collect()) {
+                        track.values = values;
+                    }
+                }
+            }
+        }
+    }
+
+    AnimData {
+        major_version: a.major_version,
+        minor_version: a.minor_version,
+        groups,
+        final_frame_index: frame_count.saturating_sub(1) as f32,
+    }
+}
+
+/// Applies the transform tracks in `additive` on top of `base`.
+/// The offset for each frame is relative to the pose of `additive` at `reference_frame`.
+/// The offsets are scaled by `weight`, so a weight of `0.0` has no effect.
+pub fn apply_additive_anim(
+    base: &AnimData,
+    additive: &AnimData,
+    reference_frame: f32,
+    weight: f32,
+) -> AnimData {
+    let frame_count = anim_frame_count(base).max(anim_frame_count(additive));
+
+    let mut groups = base.groups.clone();
+    for group in groups
+        .iter_mut()
+        .filter(|g| matches!(g.group_type, GroupType::Transform))
+    {
+        for node in &mut group.nodes {
+            for track in &mut node.tracks {
+                let additive_track =
+                    find_track(additive, &group.group_type, &node.name, &track.name);
+                let (TrackValues::Transform(base_values), Some(additive_track)) =
+                    (&track.values, additive_track)
+                else {
+                    continue;
+                };
+                let TrackValues::Transform(additive_values) = &additive_track.values else {
+                    continue;
+                };
+                if base_values.is_empty() || additive_values.is_empty() {
+                    continue;
+                }
+
+                let Some(TrackValue::Transform(reference)) =
+                    sample_track(&additive_track.values, reference_frame)
+                else {
+                    continue;
+                };
+
+                let values = (0..frame_count)
+                    .filter_map(|frame| {
+                        let base = match sample_track(&track.values, frame as f32)? {
+                            TrackValue::Transform(t) => t,
+                            _ => return None,
+                        };
+                        let additive = match sample_track(&additive_track.values, frame as f32)? {
+                            TrackValue::Transform(t) => t,
+                            _ => return None,
+                        };
+
+                        // Find the offset from the reference pose and scale it by the weight.
+                        let scale_offset = Vec3::ONE.lerp(
+                            vec3(&additive.scale) / safe_divisor(vec3(&reference.scale)),
+                            weight,
+                        );
+                        let rotation_offset = Quat::IDENTITY.slerp(
+                            quat(&reference.rotation).inverse() * quat(&additive.rotation),
+                            weight,
+                        );
+                        let translation_offset =
+                            (vec3(&additive.translation) - vec3(&reference.translation)) * weight;
+
+                        Some(transform(
+                            vec3(&base.scale) * scale_offset,
+                            (quat(&base.rotation) * rotation_offset).normalize(),
+                            vec3(&base.translation) + translation_offset,
+                        ))
+                    })
+                    .collect();
+                track.values = TrackValues::Transform(values);
+            }
+        }
+    }
+
+    AnimData {
+        major_version: base.major_version,
+        minor_version: base.minor_version,
+        groups,
+        final_frame_index: frame_count.saturating_sub(1) as f32,
+    }
+}
+
+/// Plays each anim in `anims` one after the other.
+/// Consecutive anims overlap by `crossfade_frames` frames and are linearly blended in the overlap.
+/// Tracks missing from an anim hold the value from the closest anim containing that track.
+pub fn concatenate_anims(anims: &[AnimData], crossfade_frames: usize) -> Option<AnimData> {
+    let first = anims.first()?;
+
+    // Calculate the starting frame for each anim.
+    // The overlap can't be longer than either of the anims.
+    let mut start_frames = Vec::new();
+    let mut frame_count = 0;
+    let mut previous_count = 0;
+    for anim in anims {
+        let count = anim_frame_count(anim);
+        let overlap = crossfade_frames.min(previous_count).min(count);
+        let start = frame_count - overlap;
+        start_frames.push(start);
+        frame_count = start + count;
+        previous_count = count;
+    }
+
+    let anim_refs: Vec<_> = anims.iter().collect();
+    let mut groups = combined_groups(&anim_refs);
+    for group in &mut groups {
+        for node in &mut group.nodes {
+            for track in &mut node.tracks {
+                let tracks: Vec<_> = anims
+                    .iter()
+                    .zip(&start_frames)
+                    .filter_map(|(anim, start)| {
+                        find_track(anim, &group.group_type, &node.name, &track.name)
+                            .map(|t| (*start, anim_frame_count(anim), t))
+                    })
+                    .collect();
+
+                let values = (0..frame_count)
+                    .filter_map(|frame| {
+                        // Use the most recent anim that has started and contains this track.
+                        let current = tracks
+                            .iter()
+                            .rposition(|(start, _, _)| *start <= frame)
+                            .unwrap_or(0);
+                        let (start, _, current_track) = tracks[current];
+                        let value = sample_track(
+                            &current_track.values,
+                            frame.saturating_sub(start) as f32,
+                        )?;
+
+                        // Blend from the previous anim if it hasn't finished yet.
+                        match current.checked_sub(1).map(|i| tracks[i]) {
+                            Some((previous_start, previous_count, previous_track))
+                                if frame < previous_start + previous_count =>
+                            {
+                                let overlap = previous_start + previous_count - start;
+                                let previous = sample_track(
+                                    &previous_track.values,
+                                    (frame - previous_start) as f32,
+                                )?;
+                                let factor = (frame - start + 1) as f32 / (overlap + 1) as f32;
+                                Some(lerp_value(&previous, &value, factor))
+                            }
+                            _ => Some(value),
+                        }
+                    })
+                    .collect();
+
+                if let Some(values) = collect_track_values(values) {
+                    track.values = values;
+                }
+            }
+        }
+    }
+
+    Some(AnimData {
+        major_version: first.major_version,
+        minor_version: first.minor_version,
+        groups,
+        final_frame_index: frame_count.saturating_sub(1) as f32,
+    })
+}
+
+fn safe_divisor(v: Vec3) -> Vec3 {
+    // Avoid producing infinite scale values for bones scaled to zero.
+    Vec3::select(v.abs().cmpgt(Vec3::splat(1e-6)), v, Vec3::ONE)
+}
@@ -0,0 +1,189 @@
+use glam::{Mat3, Quat, Vec3};
+use ssbh_data::anim_data::{
+    AnimData, GroupData, GroupType, NodeData, TrackData, TrackValues, TransformFlags,
+};
+
+use crate::anim_sampling::{
+    anim_frame_count, find_track, quat, sample_track, transform, vec3, TrackValue,
+};
+
+/// The node with the camera's world transform.
+pub const CAMERA_NODE: &str = "gya_camera";
+/// The node with the point the camera is aiming at.
+pub const CAMERA_EYE_NODE: &str = "gya_cameraEye";
+/// The node with the camera's projection parameters.
+pub const CAMERA_SHAPE_NODE: &str = "gya_cameraShape";
+
+/// The per frame values for a camera animation.
+/// The field of view is the vertical field of view in radians.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraValues {
+    pub position: Vec<[f32; 3]>,
+    pub target: Vec<[f32; 3]>,
+    pub up: Vec<[f32; 3]>,
+    pub field_of_view: Vec<f32>,
+    pub near_clip: Vec<f32>,
+    pub far_clip: Vec<f32>,
+}
+
+/// Samples the camera tracks in `anim` for each frame.
+///
+/// The camera looks along the negative Z-axis of the [CAMERA_NODE] transform with positive Y as up.
+/// The target uses the [CAMERA_EYE_NODE] translation if present
+/// and otherwise uses a point one unit in front of the camera.
+pub fn camera_values(anim: &AnimData) -> Result<CameraValues, String> {
+    let camera = find_track(anim, &GroupType::Transform, CAMERA_NODE, "Transform")
+        .ok_or_else(|| format!("Missing Transform track for node {CAMERA_NODE:?}."))?;
+    let eye = find_track(anim, &GroupType::Transform, CAMERA_EYE_NODE, "Transform");
+    let camera_track = |name: &str| {
+        find_track(anim, &GroupType::Camera, CAMERA_SHAPE_NODE, name)
+            .ok_or_else(|| format!("Missing {name} track for node {CAMERA_SHAPE_NODE:?}."))
+    };
+    let field_of_view = camera_track("FieldOfView")?;
+    let near_clip = camera_track("NearClip")?;
+    let far_clip = camera_track("FarClip")?;
+
+    let float = |track: &TrackData, frame: f32| match sample_track(&track.values, frame) {
+        Some(TrackValue::Float(f)) => Ok(f),
+        _ => Err(format!("Expected float values for track {:?}.", track.name)),
+    };
+
+    let mut values = CameraValues {
+        position: Vec::new(),
+        target: Vec::new(),
+        up: Vec::new(),
+        field_of_view: Vec::new(),
+        near_clip: Vec::new(),
+        far_clip: Vec::new(),
+    };
+    for i in 0..anim_frame_count(anim) {
+        let frame = i as f32;
+        let Some(TrackValue::Transform(t)) = sample_track(&camera.values, frame) else {
+            return Err(format!(
+                "Expected transform values for node {CAMERA_NODE:?}."
+            ));
+        };
+        let position = vec3(&t.translation);
+        let rotation = quat(&t.rotation);
+        let target = match eye.and_then(|eye| sample_track(&eye.values, frame)) {
+            Some(TrackValue::Transform(eye)) => vec3(&eye.translation),
+            _ => position + rotation * Vec3::NEG_Z,
+        };
+
+        values.position.push(position.to_array());
+        values.target.push(target.to_array());
+        values.up.push((rotation * Vec3::Y).to_array());
+        values.field_of_view.push(float(field_of_view, frame)?);
+        values.near_clip.push(float(near_clip, frame)?);
+        values.far_clip.push(float(far_clip, frame)?);
+    }
+    Ok(values)
+}
+
+/// Creates a camera animation from per frame values.
+/// All values must have the same number of frames.
+pub fn camera_anim(values: &CameraValues) -> Result<AnimData, String> {
+    let count = values.position.len();
+    if count == 0 {
+        return Err("Expected camera values for at least one frame.".to_string());
+    }
+    let counts = [
+        ("target", values.target.len()),
+        ("up", values.up.len()),
+        ("field_of_view", values.field_of_view.len()),
+        ("near_clip", values.near_clip.len()),
+        ("far_clip", values.far_clip.len()),
+    ];
+    if let Some((name, len)) = counts.iter().find(|(_, len)| *len != count) {
+        return Err(format!(
+            "Expected {count} values for {name} to match position but found {len}."
+        ));
+    }
+
+    let camera_transforms = values
+        .position
+        .iter()
+        .zip(&values.target)
+        .zip(&values.up)
+        .map(|((position, target), up)| {
+            let position = Vec3::from_array(*position);
+            let rotation =
+                look_rotation(position, Vec3::from_array(*target), Vec3::from_array(*up));
+            transform(Vec3::ONE, rotation, position)
+        })
+        .collect();
+    let eye_transforms = values
+        .target
+        .iter()
+        .map(|target| transform(Vec3::ONE, Quat::IDENTITY, Vec3::from_array(*target)))
+        .collect();
+
+    Ok(AnimData {
+        major_version: 2,
+        minor_version: 0,
+        groups: vec![
+            GroupData {
+                group_type: GroupType::Transform,
+                nodes: vec![
+                    node(
+                        CAMERA_NODE,
+                        "Transform",
+                        TrackValues::Transform(camera_transforms),
+                    ),
+                    node(
+                        CAMERA_EYE_NODE,
+                        "Transform",
+                        TrackValues::Transform(eye_transforms),
+                    ),
+                ],
+            },
+            GroupData {
+                group_type: GroupType::Camera,
+                nodes: vec![NodeData {
+                    name: CAMERA_SHAPE_NODE.to_string(),
+                    tracks: vec![
+                        track("FarClip", TrackValues::Float(values.far_clip.clone())),
+                        track(
+                            "FieldOfView",
+                            TrackValues::Float(values.field_of_view.clone()),
+                        ),
+                        track("NearClip", TrackValues::Float(values.near_clip.clone())),
+                    ],
+                }],
+            },
+        ],
+        final_frame_index: (count - 1) as f32,
+    })
+}
+
+// Rotate the negative Z-axis to point at the target.
+fn look_rotation(position: Vec3, target: Vec3, up: Vec3) -> Quat {
+    let back = (position - target).normalize_or_zero();
+    let right = up.cross(back).normalize_or_zero();
+    if back == Vec3::ZERO || right == Vec3::ZERO {
+        return Quat::IDENTITY;
+    }
+    let up = back.cross(right);
+    Quat::from_mat3(&Mat3::from_cols(right, up, back)).normalize()
+}
+
+fn node(name: &str, track_name: &str, values: TrackValues) -> NodeData {
+    NodeData {
+        name: name.to_string(),
+        tracks: vec![track(track_name, values)],
+    }
+}
+
+fn track(name: &str, values: TrackValues) -> TrackData {
+    TrackData {
+        name: name.to_string(),
+        compensate_scale: false,
+        transform_flags: TransformFlags {
+            override_translation: false,
+            override_rotation: false,
+            override_scale: false,
+            override_compensate_scale: false,
+        },
+        values,
+    }
+}
@@ -23,8 +23,10 @@ pub mod anim_data {
         Pyi, PyiMethods,
     };
     use map_py::{map_vec, MapPy, TypedList};
+    use numpy::{PyArray1, PyArray2};
     use pyo3::types::PyList;
     use ssbh_data::anim_data::TrackValues as TrackValuesRs;
+    use std::collections::HashSet;
 
     #[pymodule_export]
     pub use super::GroupType;
@@ -66,23 +68,19 @@ pub mod anim_data {
     }
 
     #[pyclass(get_all, set_all)]
-    #[derive(Debug, Clone, MapPy, Pyi, PyRepr, PyInit)]
+    #[derive(Debug, Clone, MapPy, Pyi, PyRepr)]
     #[map(ssbh_data::anim_data::TrackData)]
     #[pyrepr("ssbh_data_py.anim_data")]
+    #[pyi(has_methods = true)]
     pub struct TrackData {
         pub name: String,
 
-        #[pyinit(default = "false")]
         #[pyi(default = "False")]
         pub compensate_scale: bool,
 
-        #[pyinit(
-            default = "TransformFlags { override_translation: false, override_rotation: false, override_scale: false, override_compensate_scale: false}"
-        )]
         #[pyi(default = "TransformFlags()")]
         pub transform_flags: TransformFlags,
 
-        #[pyinit(default = "PyList::empty(py).into()")]
         #[pyi(
             default = "[]",
             python_type = "Union[list[UvTransform], list[Transform],
@@ -92,6 +90,86 @@ pub mod anim_data {
         pub values: Py<PyList>,
     }
 
+    #[pymethods]
+    impl TrackData {
+        #[new]
+        #[pyo3(signature = (name, compensate_scale = None, transform_flags = None, values = None))]
+        fn new(
+            py: Python,
+            name: String,
+            compensate_scale: Option<bool>,
+            transform_flags: Option<TransformFlags>,
+            values: Option<Py<PyList>>,
+        ) -> PyResult<Self> {
+            Ok(Self {
+                name,
+                compensate_scale: compensate_scale.unwrap_or(false),
+                transform_flags: transform_flags.unwrap_or(TransformFlags {
+                    override_translation: false,
+                    override_rotation: false,
+                    override_scale: false,
+                    override_compensate_scale: false,
+                }),
+                values: values.unwrap_or(PyList::empty(py).into()),
+            })
+        }
+
+        #[pyo3(signature = (tolerance = 0.0001, smoothing_window = 0, smoothing_filter = "box"))]
+        fn reduce(
+            &mut self,
+            py: Python,
+            tolerance: f32,
+            smoothing_window: usize,
+            smoothing_filter: &str,
+        ) -> PyResult<()> {
+            let filter = smoothing_filter_rs(smoothing_filter)?;
+            let values = create_track_values_rs(py, &self.values)?;
+            let values = crate::anim_reduce::reduce_track_values(
+                &values,
+                tolerance,
+                smoothing_window,
+                filter,
+            );
+            self.values = map_from_track_values(values, py)?;
+            Ok(())
+        }
+
+        fn __repr__(&self) -> String {
+            self.py_repr()
+        }
+    }
+
+    impl PyiMethods for TrackData {
+        fn pyi_methods() -> String {
+            r#"    def __init__(
+        self,
+        name: str,
+        compensate_scale: bool = False,
+        transform_flags: TransformFlags = TransformFlags(),
+        values: Union[list[UvTransform], list[Transform],
+                      list[float], list[bool], list[int], list[list[float]]] = []
+    ) -> None: ...
+
+    def reduce(
+        self,
+        tolerance: float = 0.0001,
+        smoothing_window: int = 0,
+        smoothing_filter: str = 'box',
+    ) -> None: ..."#
+                .to_string()
+        }
+    }
+
+    fn smoothing_filter_rs(filter: &str) -> PyResult<crate::anim_reduce::SmoothingFilter> {
+        match filter {
+            "box" => Ok(crate::anim_reduce::SmoothingFilter::Box),
+            "gaussian" => Ok(crate::anim_reduce::SmoothingFilter::Gaussian),
+            _ => Err(AnimDataError::new_err(format!(
+                "Unsupported smoothing filter {filter:?}. Expected 'box' or 'gaussian'."
+            ))),
+        }
+    }
+
     #[pyclass(get_all, set_all)]
     #[derive(Debug, Clone, MapPy, Pyi, PyRepr, PyInit)]
     #[map(ssbh_data::anim_data::TransformFlags)]
@@ -134,6 +212,46 @@ pub mod anim_data {
                 .map_err(|e| AnimDataError::new_err(format!("{e}")))
         }
 
+        fn convert_version(
+            &mut self,
+            py: Python,
+            major_version: u16,
+            minor_version: u16,
+        ) -> PyResult<Vec<String>> {
+            let data = self.clone().map_py(py)?;
+            let changes =
+                crate::version_convert::convert_version(&data, (major_version, minor_version))
+                    .map_err(AnimDataError::new_err)?;
+            self.major_version = major_version;
+            self.minor_version = minor_version;
+            Ok(changes)
+        }
+
+        #[pyo3(signature = (tolerance = 0.0001, smoothing_window = 0, smoothing_filter = "box"))]
+        fn reduce(
+            &self,
+            py: Python,
+            tolerance: f32,
+            smoothing_window: usize,
+            smoothing_filter: &str,
+        ) -> PyResult<()> {
+            for group in self.groups.list.bind(py).iter() {
+                let group = group.downcast::<GroupData>()?.borrow();
+                for node in group.nodes.list.bind(py).iter() {
+                    let node = node.downcast::<NodeData>()?.borrow();
+                    for track in node.tracks.list.bind(py).iter() {
+                        track.downcast::<TrackData>()?.borrow_mut().reduce(
+                            py,
+                            tolerance,
+                            smoothing_window,
+                            smoothing_filter,
+                        )?;
+                    }
+                }
+            }
+            Ok(())
+        }
+
         fn __repr__(&self) -> String {
             self.py_repr()
         }
@@ -147,7 +265,20 @@ pub mod anim_data {
         minor_version: int = 0,
     ) -> None: ...
 
-    def save(self, path: str) -> None: ..."#
+    def save(self, path: str) -> None: ...
+
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
+    def reduce(
+        self,
+        tolerance: float = 0.0001,
+        smoothing_window: int = 0,
+        smoothing_filter: str = 'box',
+    ) -> None: ..."#
                 .to_string()
         }
     }
@@ -159,6 +290,217 @@ pub mod anim_data {
             .map_py(py)
     }
 
+    #[pyfunction]
+    fn export_smd_anim(
+        py: Python,
+        anim: AnimData,
+        skel: crate::skel_data::skel_data::SkelData,
+        path: &str,
+    ) -> PyResult<()> {
+        let smd = crate::smd::write_smd_anim(&anim.map_py(py)?, &skel.map_py(py)?);
+        std::fs::write(path, smd).map_err(|e| AnimDataError::new_err(format!("{e}")))
+    }
+
+    #[pyfunction]
+    fn import_smd_anim(py: Python, path: &str) -> PyResult<AnimData> {
+        let smd =
+            std::fs::read_to_string(path).map_err(|e| AnimDataError::new_err(format!("{e}")))?;
+        crate::smd::read_smd_anim(&smd)
+            .map_err(AnimDataError::new_err)?
+            .map_py(py)
+    }
+
+    #[pyfunction]
+    fn blend_anims(
+        py: Python,
+        anim_a: AnimData,
+        anim_b: AnimData,
+        weights: Vec<f32>,
+    ) -> PyResult<AnimData> {
+        if weights.is_empty() {
+            return Err(AnimDataError::new_err(
+                "Expected at least one blend weight.",
+            ));
+        }
+        crate::anim_blend::blend_anims(&anim_a.map_py(py)?, &anim_b.map_py(py)?, &weights)
+            .map_py(py)
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (base, additive, reference_frame = 0.0, weight = 1.0))]
+    fn apply_additive_anim(
+        py: Python,
+        base: AnimData,
+        additive: AnimData,
+        reference_frame: f32,
+        weight: f32,
+    ) -> PyResult<AnimData> {
+        crate::anim_blend::apply_additive_anim(
+            &base.map_py(py)?,
+            &additive.map_py(py)?,
+            reference_frame,
+            weight,
+        )
+        .map_py(py)
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (anims, crossfade_frames = 0))]
+    fn concatenate_anims(
+        py: Python,
+        anims: Vec<AnimData>,
+        crossfade_frames: usize,
+    ) -> PyResult<AnimData> {
+        let anims = anims
+            .into_iter()
+            .map(|a| a.map_py(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        crate::anim_blend::concatenate_anims(&anims, crossfade_frames)
+            .ok_or(AnimDataError::new_err("Expected at least one anim."))?
+            .map_py(py)
+    }
+
+    #[pyfunction]
+    fn evaluate_material_anim(
+        py: Python,
+        anim: AnimData,
+        matl: crate::matl_data::matl_data::MatlData,
+        frame: f32,
+    ) -> PyResult<(crate::matl_data::matl_data::MatlData, Vec<String>)> {
+        let (matl, missing) = crate::anim_material::evaluate_material_anim(
+            &anim.map_py(py)?,
+            &matl.map_py(py)?,
+            frame,
+        );
+        Ok((matl.map_py(py)?, missing))
+    }
+
+    #[pyfunction]
+    fn visible_mesh_objects(
+        py: Python,
+        anim: AnimData,
+        mesh: PyRef<crate::mesh_data::mesh_data::MeshData>,
+        frame: f32,
+    ) -> PyResult<Vec<Py<crate::mesh_data::mesh_data::MeshObjectData>>> {
+        let anim = anim.map_py(py)?;
+        let mut objects = Vec::new();
+        for object in mesh.objects.list.bind(py).iter() {
+            let object = object.downcast::<crate::mesh_data::mesh_data::MeshObjectData>()?;
+            if crate::anim_visibility::is_visible(&anim, &object.borrow().name, frame) {
+                objects.push(object.clone().unbind());
+            }
+        }
+        Ok(objects)
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (visible_names, node_names = None))]
+    fn create_visibility_group(
+        py: Python,
+        visible_names: Vec<HashSet<String>>,
+        node_names: Option<Vec<String>>,
+    ) -> PyResult<GroupData> {
+        if visible_names.is_empty() {
+            return Err(AnimDataError::new_err(
+                "Expected visible names for at least one frame.",
+            ));
+        }
+        crate::anim_visibility::create_visibility_group(
+            &visible_names,
+            &node_names.unwrap_or_default(),
+        )
+        .map_py(py)
+    }
+
+    #[pyclass(get_all, set_all)]
+    #[derive(Debug, Clone, MapPy, Pyi, PyRepr)]
+    #[map(crate::anim_camera::CameraValues)]
+    #[pyrepr("ssbh_data_py.anim_data")]
+    #[pyi(has_methods = true)]
+    pub struct CameraValues {
+        pub position: Py<PyArray2<f32>>,
+
+        pub target: Py<PyArray2<f32>>,
+
+        pub up: Py<PyArray2<f32>>,
+
+        #[map(from(map_from_floats), into(map_into_floats))]
+        pub field_of_view: Py<PyArray1<f32>>,
+
+        #[map(from(map_from_floats), into(map_into_floats))]
+        pub near_clip: Py<PyArray1<f32>>,
+
+        #[map(from(map_from_floats), into(map_into_floats))]
+        pub far_clip: Py<PyArray1<f32>>,
+    }
+
+    #[pymethods]
+    impl CameraValues {
+        #[new]
+        fn new(
+            position: Py<PyArray2<f32>>,
+            target: Py<PyArray2<f32>>,
+            up: Py<PyArray2<f32>>,
+            field_of_view: Py<PyArray1<f32>>,
+            near_clip: Py<PyArray1<f32>>,
+            far_clip: Py<PyArray1<f32>>,
+        ) -> PyResult<Self> {
+            Ok(Self {
+                position,
+                target,
+                up,
+                field_of_view,
+                near_clip,
+                far_clip,
+            })
+        }
+
+        #[staticmethod]
+        fn from_anim(py: Python, anim: AnimData) -> PyResult<Self> {
+            crate::anim_camera::camera_values(&anim.map_py(py)?)
+                .map_err(AnimDataError::new_err)?
+                .map_py(py)
+        }
+
+        fn to_anim(&self, py: Python) -> PyResult<AnimData> {
+            crate::anim_camera::camera_anim(&self.clone().map_py(py)?)
+                .map_err(AnimDataError::new_err)?
+                .map_py(py)
+        }
+
+        fn __repr__(&self) -> String {
+            self.py_repr()
+        }
+    }
+
+    impl PyiMethods for CameraValues {
+        fn pyi_methods() -> String {
+            r#"    def __init__(
+        self,
+        position: numpy.ndarray,
+        target: numpy.ndarray,
+        up: numpy.ndarray,
+        field_of_view: numpy.ndarray,
+        near_clip: numpy.ndarray,
+        far_clip: numpy.ndarray,
+    ) -> None: ...
+
+    @staticmethod
+    def from_anim(anim: AnimData) -> CameraValues: ...
+
+    def to_anim(self) -> AnimData: ..."#
+                .to_string()
+        }
+    }
+
+    fn map_from_floats(value: Vec<f32>, py: Python) -> PyResult<Py<PyArray1<f32>>> {
+        Ok(PyArray1::from_vec(py, value).unbind())
+    }
+
+    fn map_into_floats(value: Py<PyArray1<f32>>, py: Python) -> PyResult<Vec<f32>> {
+        value.extract(py)
+    }
+
     // TODO: Document what component counts are expected.
     #[pyclass(get_all, set_all)]
     #[derive(Debug, Clone, MapPy, Pyi, PyRepr, PyInit)]
@@ -0,0 +1,103 @@
+use ssbh_data::anim_data::{AnimData, GroupType};
+use ssbh_data::matl_data::{MatlData, MatlEntryData, UvTransform};
+
+use crate::anim_sampling::{sample_track, TrackValue};
+
+/// Applies the material tracks in `anim` at `frame` to the matching entries in `matl`.
+/// Nodes are matched by material label and tracks are matched by parameter name.
+/// Returns the modified materials and a message for each track that could not be applied.
+pub fn evaluate_material_anim(
+    anim: &AnimData,
+    matl: &MatlData,
+    frame: f32,
+) -> (MatlData, Vec<String>) {
+    let mut matl = matl.clone();
+    let mut missing = Vec::new();
+
+    for group in anim
+        .groups
+        .iter()
+        .filter(|g| matches!(g.group_type, GroupType::Material))
+    {
+        for node in &group.nodes {
+            let mut entries: Vec<_> = matl
+                .entries
+                .iter_mut()
+                .filter(|e| e.material_label == node.name)
+                .collect();
+            if entries.is_empty() {
+                missing.push(format!("Material {:?} not found.", node.name));
+                continue;
+            }
+
+            for track in &node.tracks {
+                let Some(value) = sample_track(&track.values, frame) else {
+                    continue;
+                };
+
+                for entry in &mut entries {
+                    if !apply_param(entry, &track.name, &value) {
+                        missing.push(format!(
+                            "Parameter {} with type {} not found for material {:?}.",
+                            track.name,
+                            value_type(&value),
+                            node.name
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    (matl, missing)
+}
+
+fn apply_param(entry: &mut MatlEntryData, name: &str, value: &TrackValue) -> bool {
+    match value {
+        TrackValue::Float(f) => entry
+            .floats
+            .iter_mut()
+            .find(|p| p.param_id.to_string() == name)
+            .map(|p| p.data = *f)
+            .is_some(),
+        TrackValue::Boolean(b) => entry
+            .booleans
+            .iter_mut()
+            .find(|p| p.param_id.to_string() == name)
+            .map(|p| p.data = *b)
+            .is_some(),
+        TrackValue::Vector4(v) => entry
+            .vectors
+            .iter_mut()
+            .find(|p| p.param_id.to_string() == name)
+            .map(|p| p.data = *v)
+            .is_some(),
+        TrackValue::UvTransform(t) => entry
+            .uv_transforms
+            .iter_mut()
+            .find(|p| p.param_id.to_string() == name)
+            .map(|p| {
+                p.data = UvTransform {
+                    scale_u: t.scale_u,
+                    scale_v: t.scale_v,
+                    rotation: t.rotation,
+                    translate_u: t.translate_u,
+                    translate_v: t.translate_v,
+                }
+            })
+            .is_some(),
+        // Material parameters don't store transforms or texture pattern indices.
+        TrackValue::Transform(_) | TrackValue::PatternIndex(_) => false,
+    }
+}
+
+fn value_type(value: &TrackValue) -> &'static str {
+    match value {
+        TrackValue::Transform(_) => "Transform",
+        TrackValue::UvTransform(_) => "UvTransform",
+        TrackValue::Float(_) => "Float",
+        TrackValue::PatternIndex(_) => "PatternIndex",
+        TrackValue::Boolean(_) => "Boolean",
+        TrackValue::Vector4(_) => "Vector4",
+    }
+}
@@ -0,0 +1,205 @@
+use ssbh_data::anim_data::{TrackValues, UvTransform};
+use ssbh_data::Vector4;
+
+use crate::anim_sampling::{
+    collect_track_values, lerp_value, track_frame_count, track_value, transform, TrackValue,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmoothingFilter {
+    Box,
+    Gaussian,
+}
+
+/// Reduces `values` to linear interpolation between keyframes
+/// while keeping every frame within `tolerance` of the original values.
+///
+/// [TrackValues] stores one value per frame, so the frame count can't be reduced for most tracks.
+/// Frames that can be reconstructed from neighboring keyframes are replaced with the interpolated value.
+/// Only constant tracks with all values within `tolerance` of the first value get smaller
+/// by replacing them with a single value.
+/// Continuous values are smoothed before reducing if `smoothing_window` is greater than one.
+pub fn reduce_track_values(
+    values: &TrackValues,
+    tolerance: f32,
+    smoothing_window: usize,
+    filter: SmoothingFilter,
+) -> TrackValues {
+    let frames: Vec<_> = (0..track_frame_count(values))
+        .filter_map(|i| track_value(values, i))
+        .collect();
+    if frames.len() <= 1 {
+        return values.clone();
+    }
+
+    let frames = if smoothing_window > 1 {
+        smooth_frames(&frames, smoothing_window, filter)
+    } else {
+        frames
+    };
+
+    if frames
+        .iter()
+        .all(|v| value_error(&frames[0], v) <= tolerance)
+    {
+        return collect_track_values(vec![frames[0].clone()]).unwrap_or_else(|| values.clone());
+    }
+
+    let keyframes = find_keyframes(&frames, tolerance);
+    let reduced = keyframes
+        .windows(2)
+        .flat_map(|keys| {
+            let (start, end) = (keys[0], keys[1]);
+            let frames = &frames;
+            (start..end).map(move |i| {
+                let factor = (i - start) as f32 / (end - start) as f32;
+                lerp_value(&frames[start], &frames[end], factor)
+            })
+        })
+        .chain(frames.last().cloned())
+        .collect();
+    collect_track_values(reduced).unwrap_or_else(|| values.clone())
+}
+
+/// Finds the frame indices that reconstruct all other frames within `tolerance`.
+/// The first and last frames are always included.
+pub fn find_keyframes(frames: &[TrackValue], tolerance: f32) -> Vec<usize> {
+    let mut keyframes = vec![0];
+    let mut start = 0;
+    while start < frames.len() - 1 {
+        // Extend the segment as long as the frames in between can be interpolated.
+        let mut end = start + 1;
+        while end + 1 < frames.len() && can_interpolate(frames, start, end + 1, tolerance) {
+            end += 1;
+        }
+        keyframes.push(end);
+        start = end;
+    }
+    keyframes
+}
+
+fn can_interpolate(frames: &[TrackValue], start: usize, end: usize, tolerance: f32) -> bool {
+    (start + 1..end).all(|i| {
+        let factor = (i - start) as f32 / (end - start) as f32;
+        let interpolated = lerp_value(&frames[start], &frames[end], factor);
+        value_error(&interpolated, &frames[i]) <= tolerance
+    })
+}
+
+fn smooth_frames(frames: &[TrackValue], window: usize, filter: SmoothingFilter) -> Vec<TrackValue> {
+    let radius = window / 2;
+    let weights: Vec<f32> = (0..=2 * radius)
+        .map(|i| match filter {
+            SmoothingFilter::Box => 1.0,
+            SmoothingFilter::Gaussian => {
+                // Most of the distribution should fit within the window.
+                let sigma = (radius as f32 / 2.0).max(0.5);
+                let x = i as f32 - radius as f32;
+                (-(x * x) / (2.0 * sigma * sigma)).exp()
+            }
+        })
+        .collect();
+
+    (0..frames.len())
+        .map(|i| {
+            let Some(center) = components(&frames[i]) else {
+                // Discrete values like booleans can't be averaged.
+                return frames[i].clone();
+            };
+
+            let mut sum = vec![0.0; center.len()];
+            let mut weight_sum = 0.0;
+            for (offset, weight) in weights.iter().enumerate() {
+                // Clamp to the edges to avoid shrinking the values at the start and end.
+                let j = (i + offset).saturating_sub(radius).min(frames.len() - 1);
+                if let Some(mut c) = components(&frames[j]) {
+                    align_rotation(&frames[i], &center, &mut c);
+                    for (s, c) in sum.iter_mut().zip(c) {
+                        *s += c * weight;
+                    }
+                    weight_sum += weight;
+                }
+            }
+            let average: Vec<_> = sum.iter().map(|s| s / weight_sum).collect();
+            from_components(&frames[i], &average)
+        })
+        .collect()
+}
+
+fn value_error(a: &TrackValue, b: &TrackValue) -> f32 {
+    match (components(a), components(b)) {
+        (Some(ca), Some(mut cb)) => {
+            align_rotation(a, &ca, &mut cb);
+            ca.iter()
+                .zip(cb)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f32::max)
+        }
+        _ => {
+            if a == b {
+                0.0
+            } else {
+                f32::INFINITY
+            }
+        }
+    }
+}
+
+// Use the same sign as the reference quaternion since q and -q are the same rotation.
+fn align_rotation(value: &TrackValue, reference: &[f32], components: &mut [f32]) {
+    if let TrackValue::Transform(_) = value {
+        let dot: f32 = (3..7).map(|i| reference[i] * components[i]).sum();
+        if dot < 0.0 {
+            for c in &mut components[3..7] {
+                *c = -*c;
+            }
+        }
+    }
+}
+
+fn components(value: &TrackValue) -> Option<Vec<f32>> {
+    match value {
+        TrackValue::Transform(t) => Some(vec![
+            t.scale.x,
+            t.scale.y,
+            t.scale.z,
+            t.rotation.x,
+            t.rotation.y,
+            t.rotation.z,
+            t.rotation.w,
+            t.translation.x,
+            t.translation.y,
+            t.translation.z,
+        ]),
+        TrackValue::UvTransform(t) => Some(vec![
+            t.scale_u,
+            t.scale_v,
+            t.rotation,
+            t.translate_u,
+            t.translate_v,
+        ]),
+        TrackValue::Float(f) => Some(vec![*f]),
+        TrackValue::Vector4(v) => Some(vec![v.x, v.y, v.z, v.w]),
+        TrackValue::PatternIndex(_) | TrackValue::Boolean(_) => None,
+    }
+}
+
+fn from_components(value: &TrackValue, c: &[f32]) -> TrackValue {
+    match value {
+        TrackValue::Transform(_) => TrackValue::Transform(transform(
+            glam::Vec3::new(c[0], c[1], c[2]),
+            glam::Quat::from_xyzw(c[3], c[4], c[5], c[6]).normalize(),
+            glam::Vec3::new(c[7], c[8], c[9]),
+        )),
+        TrackValue::UvTransform(_) => TrackValue::UvTransform(UvTransform {
+            scale_u: c[0],
+            scale_v: c[1],
+            rotation: c[2],
+            translate_u: c[3],
+            translate_v: c[4],
+        }),
+        TrackValue::Float(_) => TrackValue::Float(c[0]),
+        TrackValue::Vector4(_) => TrackValue::Vector4(Vector4::new(c[0], c[1], c[2], c[3])),
+        TrackValue::PatternIndex(_) | TrackValue::Boolean(_) => value.clone(),
+    }
+}
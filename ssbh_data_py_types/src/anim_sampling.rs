@@ -0,0 +1,209 @@
+use glam::{Quat, Vec3};
+use ssbh_data::anim_data::{AnimData, GroupData, GroupType, TrackData, TrackValues};
+use ssbh_data::anim_data::{Transform, UvTransform};
+use ssbh_data::{Vector3, Vector4};
+
+/// The value for a single frame of a [TrackValues].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackValue {
+    Transform(Transform),
+    UvTransform(UvTransform),
+    Float(f32),
+    PatternIndex(u32),
+    Boolean(bool),
+    Vector4(Vector4),
+}
+
+/// The number of frames in `anim` including the final frame.
+pub fn anim_frame_count(anim: &AnimData) -> usize {
+    anim.final_frame_index.max(0.0).floor() as usize + 1
+}
+
+pub fn track_frame_count(values: &TrackValues) -> usize {
+    match values {
+        TrackValues::Transform(v) => v.len(),
+        TrackValues::UvTransform(v) => v.len(),
+        TrackValues::Float(v) => v.len(),
+        TrackValues::PatternIndex(v) => v.len(),
+        TrackValues::Boolean(v) => v.len(),
+        TrackValues::Vector4(v) => v.len(),
+    }
+}
+
+/// Returns the value at `index` or `None` if `index` is out of range.
+pub fn track_value(values: &TrackValues, index: usize) -> Option<TrackValue> {
+    match values {
+        TrackValues::Transform(v) => v.get(index).cloned().map(TrackValue::Transform),
+        TrackValues::UvTransform(v) => v.get(index).cloned().map(TrackValue::UvTransform),
+        TrackValues::Float(v) => v.get(index).copied().map(TrackValue::Float),
+        TrackValues::PatternIndex(v) => v.get(index).copied().map(TrackValue::PatternIndex),
+        TrackValues::Boolean(v) => v.get(index).copied().map(TrackValue::Boolean),
+        TrackValues::Vector4(v) => v.get(index).copied().map(TrackValue::Vector4),
+    }
+}
+
+/// Samples `values` at `frame` by interpolating between the nearest frames.
+/// Frames outside the track are clamped, so single frame tracks are constant.
+pub fn sample_track(values: &TrackValues, frame: f32) -> Option<TrackValue> {
+    let count = track_frame_count(values);
+    if count == 0 {
+        return None;
+    }
+
+    let frame = frame.clamp(0.0, (count - 1) as f32);
+    let start = frame.floor() as usize;
+    let end = (start + 1).min(count - 1);
+    let factor = frame - start as f32;
+
+    let a = track_value(values, start)?;
+    let b = track_value(values, end)?;
+    Some(lerp_value(&a, &b, factor))
+}
+
+/// Linearly interpolates between `a` and `b` with rotations using slerp.
+/// Discrete values like booleans and mismatched types switch at `factor = 0.5`.
+pub fn lerp_value(a: &TrackValue, b: &TrackValue, factor: f32) -> TrackValue {
+    match (a, b) {
+        (TrackValue::Transform(a), TrackValue::Transform(b)) => {
+            TrackValue::Transform(lerp_transform(a, b, factor))
+        }
+        (TrackValue::UvTransform(a), TrackValue::UvTransform(b)) => {
+            TrackValue::UvTransform(UvTransform {
+                scale_u: lerp(a.scale_u, b.scale_u, factor),
+                scale_v: lerp(a.scale_v, b.scale_v, factor),
+                rotation: lerp(a.rotation, b.rotation, factor),
+                translate_u: lerp(a.translate_u, b.translate_u, factor),
+                translate_v: lerp(a.translate_v, b.translate_v, factor),
+            })
+        }
+        (TrackValue::Float(a), TrackValue::Float(b)) => TrackValue::Float(lerp(*a, *b, factor)),
+        (TrackValue::Vector4(a), TrackValue::Vector4(b)) => TrackValue::Vector4(Vector4::new(
+            lerp(a.x, b.x, factor),
+            lerp(a.y, b.y, factor),
+            lerp(a.z, b.z, factor),
+            lerp(a.w, b.w, factor),
+        )),
+        _ => {
+            if factor < 0.5 {
+                a.clone()
+            } else {
+                b.clone()
+            }
+        }
+    }
+}
+
+pub fn lerp_transform(a: &Transform, b: &Transform, factor: f32) -> Transform {
+    transform(
+        vec3(&a.scale).lerp(vec3(&b.scale), factor),
+        quat(&a.rotation).slerp(quat(&b.rotation), factor),
+        vec3(&a.translation).lerp(vec3(&b.translation), factor),
+    )
+}
+
+/// Converts per frame values back into [TrackValues].
+/// Returns `None` if `values` is empty or contains more than one type of value.
+pub fn collect_track_values(values: Vec<TrackValue>) -> Option<TrackValues> {
+    macro_rules! collect {
+        ($variant:ident) => {
+            values
+                .into_iter()
+                .map(|v| match v {
+                    TrackValue::$variant(v) => Some(v),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(TrackValues::$variant)
+        };
+    }
+
+    match values.first()? {
+        TrackValue::Transform(_) => collect!(Transform),
+        TrackValue::UvTransform(_) => collect!(UvTransform),
+        TrackValue::Float(_) => collect!(Float),
+        TrackValue::PatternIndex(_) => collect!(PatternIndex),
+        TrackValue::Boolean(_) => collect!(Boolean),
+        TrackValue::Vector4(_) => collect!(Vector4),
+    }
+}
+
+pub fn find_group<'a>(anim: &'a AnimData, group_type: &GroupType) -> Option<&'a GroupData> {
+    anim.groups
+        .iter()
+        .find(|g| std::mem::discriminant(&g.group_type) == std::mem::discriminant(group_type))
+}
+
+pub fn find_track<'a>(
+    anim: &'a AnimData,
+    group_type: &GroupType,
+    node_name: &str,
+    track_name: &str,
+) -> Option<&'a TrackData> {
+    find_group(anim, group_type)?
+        .nodes
+        .iter()
+        .find(|n| n.name == node_name)?
+        .tracks
+        .iter()
+        .find(|t| t.name == track_name)
+}
+
+/// Combines the groups, nodes, and tracks from all `anims` by group type and name.
+/// The track data for each track is cloned from the first anim containing that track.
+pub fn combined_groups(anims: &[&AnimData]) -> Vec<GroupData> {
+    let mut groups: Vec<GroupData> = Vec::new();
+    for anim in anims {
+        for group in &anim.groups {
+            let combined_group = match groups.iter().position(|g| {
+                std::mem::discriminant(&g.group_type) == std::mem::discriminant(&group.group_type)
+            }) {
+                Some(i) => &mut groups[i],
+                None => {
+                    groups.push(GroupData {
+                        group_type: group.group_type,
+                        nodes: Vec::new(),
+                    });
+                    groups.last_mut().unwrap()
+                }
+            };
+
+            for node in &group.nodes {
+                match combined_group
+                    .nodes
+                    .iter_mut()
+                    .find(|n| n.name == node.name)
+                {
+                    Some(combined_node) => {
+                        for track in &node.tracks {
+                            if !combined_node.tracks.iter().any(|t| t.name == track.name) {
+                                combined_node.tracks.push(track.clone());
+                            }
+                        }
+                    }
+                    None => combined_group.nodes.push(node.clone()),
+                }
+            }
+        }
+    }
+    groups
+}
+
+pub fn vec3(v: &Vector3) -> Vec3 {
+    Vec3::new(v.x, v.y, v.z)
+}
+
+pub fn quat(v: &Vector4) -> Quat {
+    Quat::from_xyzw(v.x, v.y, v.z, v.w)
+}
+
+pub fn transform(scale: Vec3, rotation: Quat, translation: Vec3) -> Transform {
+    Transform {
+        scale: Vector3::new(scale.x, scale.y, scale.z),
+        rotation: Vector4::new(rotation.x, rotation.y, rotation.z, rotation.w),
+        translation: Vector3::new(translation.x, translation.y, translation.z),
+    }
+}
+
+fn lerp(a: f32, b: f32, factor: f32) -> f32 {
+    a + (b - a) * factor
+}
@@ -0,0 +1,95 @@
+use std::collections::{BTreeSet, HashSet};
+
+use ssbh_data::anim_data::{
+    AnimData, GroupData, GroupType, NodeData, TrackData, TrackValues, TransformFlags,
+};
+
+use crate::anim_sampling::{track_frame_count, track_value, TrackValue};
+
+/// The name of the visibility node that affects the mesh object `name`.
+///
+/// Mesh objects are named like `EyeL_VIS_O_OBJShape` with a visibility node named `EyeL`.
+/// Objects without a `_VIS` suffix use the full name.
+/// Numbered duplicates like `EyeL_VIS_O_OBJShape.001` from modeling programs use the same node.
+pub fn visibility_node_name(name: &str) -> &str {
+    let name = match name.rsplit_once('.') {
+        Some((base, number))
+            if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            base
+        }
+        _ => name,
+    };
+    name.find("_VIS").map(|i| &name[..i]).unwrap_or(name)
+}
+
+/// Returns `true` if the mesh object `name` is visible at `frame`.
+/// Visibility is stepped, so fractional frames use the value of the previous frame.
+/// Objects without a matching visibility track are always visible.
+pub fn is_visible(anim: &AnimData, name: &str, frame: f32) -> bool {
+    let node_name = visibility_node_name(name);
+    anim.groups
+        .iter()
+        .filter(|g| matches!(g.group_type, GroupType::Visibility))
+        .flat_map(|g| g.nodes.iter())
+        .filter(|n| n.name == node_name)
+        .flat_map(|n| n.tracks.iter())
+        .find_map(|t| match step_track(&t.values, frame) {
+            Some(TrackValue::Boolean(visible)) => Some(visible),
+            _ => None,
+        })
+        .unwrap_or(true)
+}
+
+fn step_track(values: &TrackValues, frame: f32) -> Option<TrackValue> {
+    let count = track_frame_count(values);
+    if count == 0 {
+        return None;
+    }
+    let index = frame.clamp(0.0, (count - 1) as f32).floor() as usize;
+    track_value(values, index)
+}
+
+/// Creates a visibility group with a track for each node.
+/// Each item in `visible_names` is the set of visible node or mesh object names for that frame.
+/// Nodes in `node_names` that never appear in `visible_names` are hidden for all frames.
+pub fn create_visibility_group(
+    visible_names: &[HashSet<String>],
+    node_names: &[String],
+) -> GroupData {
+    let visible_nodes: Vec<HashSet<&str>> = visible_names
+        .iter()
+        .map(|names| names.iter().map(|n| visibility_node_name(n)).collect())
+        .collect();
+
+    // Sort the names for consistent output.
+    let names: BTreeSet<&str> = visible_nodes
+        .iter()
+        .flatten()
+        .copied()
+        .chain(node_names.iter().map(|n| visibility_node_name(n)))
+        .collect();
+
+    GroupData {
+        group_type: GroupType::Visibility,
+        nodes: names
+            .into_iter()
+            .map(|name| NodeData {
+                name: name.to_string(),
+                tracks: vec![TrackData {
+                    name: "Visibility".to_string(),
+                    compensate_scale: false,
+                    transform_flags: TransformFlags {
+                        override_translation: false,
+                        override_rotation: false,
+                        override_scale: false,
+                        override_compensate_scale: false,
+                    },
+                    values: TrackValues::Boolean(
+                        visible_nodes.iter().map(|n| n.contains(name)).collect(),
+                    ),
+                }],
+            })
+            .collect(),
+    }
+}
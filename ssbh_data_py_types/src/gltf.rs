@@ -0,0 +1,53 @@
+use pyo3::{create_exception, prelude::*};
+
+create_exception!(ssbh_data_py, GltfError, pyo3::exceptions::PyException);
+
+#[pymodule]
+pub mod gltf {
+    pub use super::*;
+
+    use crate::anim_data::anim_data::AnimData;
+    use crate::matl_data::matl_data::MatlData;
+    use crate::mesh_data::mesh_data::MeshData;
+    use crate::modl_data::modl_data::ModlData;
+    use crate::skel_data::skel_data::SkelData;
+    use map_py::MapPy;
+
+    #[pyfunction]
+    #[pyo3(signature = (path, modl, mesh, skel, matl, anim = None))]
+    fn export_gltf(
+        py: Python,
+        path: &str,
+        modl: ModlData,
+        mesh: MeshData,
+        skel: SkelData,
+        matl: MatlData,
+        anim: Option<AnimData>,
+    ) -> PyResult<Vec<String>> {
+        let anim = anim.map(|a| a.map_py(py)).transpose()?;
+        crate::gltf_export::write_gltf(
+            std::path::Path::new(path),
+            &modl.map_py(py)?,
+            &mesh.map_py(py)?,
+            &skel.map_py(py)?,
+            &matl.map_py(py)?,
+            anim.as_ref(),
+        )
+        .map_err(GltfError::new_err)
+    }
+
+    #[pyfunction]
+    fn import_gltf(
+        py: Python,
+        path: &str,
+    ) -> PyResult<(MeshData, SkelData, AnimData, Vec<String>)> {
+        let import = crate::gltf_import::read_gltf(std::path::Path::new(path))
+            .map_err(GltfError::new_err)?;
+        Ok((
+            import.mesh.map_py(py)?,
+            import.skel.map_py(py)?,
+            import.anim.map_py(py)?,
+            import.dropped,
+        ))
+    }
+}
@@ -0,0 +1,644 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use glam::Mat4;
+use serde_json::{json, Value};
+use ssbh_data::anim_data::{AnimData, GroupType, TrackValues};
+use ssbh_data::matl_data::{BlendFactor, CullMode, MatlData, MatlEntryData, ParamId};
+use ssbh_data::mesh_data::{MeshData, MeshObjectData};
+use ssbh_data::modl_data::ModlData;
+use ssbh_data::skel_data::SkelData;
+
+use crate::anim_sampling::{anim_frame_count, sample_track, TrackValue};
+use crate::vector_data::{vectors2, vectors3, vectors4};
+
+// Smash Ultimate animations play at 60 frames per second.
+const FRAMES_PER_SECOND: f32 = 60.0;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_SHORT: u32 = 5123;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Writes the model to `path` as a binary .glb file or a .gltf file with a separate .bin file
+/// and returns a message for each change made to the data while exporting.
+pub fn write_gltf(
+    path: &Path,
+    modl: &ModlData,
+    mesh: &MeshData,
+    skel: &SkelData,
+    matl: &MatlData,
+    anim: Option<&AnimData>,
+) -> Result<Vec<String>, String> {
+    let (mut root, buffer, warnings) = create_gltf(modl, mesh, skel, matl, anim)?;
+
+    let is_glb = path
+        .extension()
+        .map(|e| e.eq_ignore_ascii_case("glb"))
+        .unwrap_or_default();
+    if is_glb {
+        root["buffers"] = json!([{ "byteLength": buffer.len() }]);
+        let json = serde_json::to_vec(&root).map_err(|e| e.to_string())?;
+        std::fs::write(path, glb_bytes(json, buffer)).map_err(|e| e.to_string())?;
+    } else {
+        let bin_path = path.with_extension("bin");
+        let bin_name = bin_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        root["buffers"] = json!([{ "byteLength": buffer.len(), "uri": bin_name }]);
+        let json = serde_json::to_vec_pretty(&root).map_err(|e| e.to_string())?;
+        std::fs::write(&bin_path, buffer).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())?;
+    }
+    Ok(warnings)
+}
+
+/// Creates the glTF JSON and binary buffer data for the model.
+/// The `buffers` for the JSON are left empty since they depend on the output file type.
+///
+/// Each bone becomes a joint node, and mesh objects with vertex weights are skinned.
+/// Mesh objects without vertex weights are parented to the node for their parent bone.
+/// Textures are referenced as PNG images with the same name as the texture.
+/// Skinned vertices without weights for any bone in `skel` are fully weighted
+/// to the parent bone or the first bone and reported in the returned messages.
+pub fn create_gltf(
+    modl: &ModlData,
+    mesh: &MeshData,
+    skel: &SkelData,
+    matl: &MatlData,
+    anim: Option<&AnimData>,
+) -> Result<(Value, Vec<u8>, Vec<String>), String> {
+    let mut buffer = BufferBuilder::default();
+    let mut warnings = Vec::new();
+
+    // Add bones first so node indices match bone indices.
+    let mut nodes: Vec<Value> = skel
+        .bones
+        .iter()
+        .map(|bone| {
+            let (scale, rotation, translation) =
+                Mat4::from_cols_array_2d(&bone.transform).to_scale_rotation_translation();
+            json!({
+                "name": bone.name,
+                "translation": translation.to_array(),
+                "rotation": rotation.normalize().to_array(),
+                "scale": scale.to_array(),
+            })
+        })
+        .collect();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); skel.bones.len()];
+    let mut scene_nodes = Vec::new();
+    for (i, bone) in skel.bones.iter().enumerate() {
+        match bone.parent_index.filter(|p| *p < skel.bones.len()) {
+            Some(parent) => children[parent].push(i),
+            None => scene_nodes.push(i),
+        }
+    }
+
+    let mut skins = Vec::new();
+    if !skel.bones.is_empty() {
+        let inverse_bind_matrices = skel
+            .bones
+            .iter()
+            .map(|bone| {
+                skel.calculate_world_transform(bone)
+                    .map(|t| Mat4::from_cols_array_2d(&t).inverse().to_cols_array())
+                    .map_err(|e| format!("{e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let accessor = buffer.add_floats(&inverse_bind_matrices, "MAT4", None, false);
+        skins.push(json!({
+            "joints": (0..skel.bones.len()).collect::<Vec<_>>(),
+            "inverseBindMatrices": accessor,
+        }));
+    }
+
+    let mut materials = MaterialBuilder::default();
+    let mut meshes = Vec::new();
+    for object in &mesh.objects {
+        let material = modl
+            .entries
+            .iter()
+            .find(|e| {
+                e.mesh_object_name == object.name && e.mesh_object_subindex == object.subindex
+            })
+            .and_then(|e| {
+                let entry = matl
+                    .entries
+                    .iter()
+                    .find(|m| m.material_label == e.material_label)?;
+                Some(materials.add(entry))
+            });
+
+        let Some(primitive) = create_primitive(&mut buffer, object, skel, material, &mut warnings)?
+        else {
+            continue;
+        };
+        meshes.push(json!({ "name": object.name, "primitives": [primitive] }));
+
+        let node_index = nodes.len();
+        let mut node = json!({ "name": object.name, "mesh": meshes.len() - 1 });
+        if !object.bone_influences.is_empty() && !skins.is_empty() {
+            node["skin"] = json!(0);
+            scene_nodes.push(node_index);
+        } else if let Some(parent) = skel
+            .bones
+            .iter()
+            .position(|b| b.name == object.parent_bone_name)
+        {
+            // Vertices are already relative to the parent bone.
+            children[parent].push(node_index);
+        } else {
+            scene_nodes.push(node_index);
+        }
+        nodes.push(node);
+    }
+
+    for (node, children) in nodes.iter_mut().zip(children) {
+        if !children.is_empty() {
+            node["children"] = json!(children);
+        }
+    }
+
+    let animations: Vec<Value> = anim
+        .and_then(|anim| create_animation(&mut buffer, anim, skel))
+        .into_iter()
+        .collect();
+
+    let mut root = json!({
+        "asset": { "version": "2.0", "generator": "ssbh_data_py" },
+        "scene": 0,
+        "scenes": [{ "nodes": scene_nodes }],
+        "nodes": nodes,
+        "meshes": meshes,
+    });
+    let mut insert = |key: &str, values: Vec<Value>| {
+        if !values.is_empty() {
+            root[key] = Value::Array(values);
+        }
+    };
+    insert("materials", materials.materials);
+    insert("textures", materials.textures);
+    insert("images", materials.images);
+    if !materials.image_indices.is_empty() {
+        insert("samplers", vec![json!({ "wrapS": 10497, "wrapT": 10497 })]);
+    }
+    insert("skins", skins);
+    insert("animations", animations);
+    insert("accessors", buffer.accessors);
+    insert("bufferViews", buffer.views);
+
+    Ok((root, buffer.data, warnings))
+}
+
+fn create_primitive(
+    buffer: &mut BufferBuilder,
+    object: &MeshObjectData,
+    skel: &SkelData,
+    material: Option<usize>,
+    warnings: &mut Vec<String>,
+) -> Result<Option<Value>, String> {
+    let Some(positions) = object.positions.first() else {
+        return Ok(None);
+    };
+    let positions = vectors3(&positions.data);
+    let vertex_count = positions.len();
+
+    let check_count = |name: &str, count: usize| {
+        if count == vertex_count {
+            Ok(())
+        } else {
+            Err(format!(
+                "Mesh object {:?} has {vertex_count} positions but {count} values for {name:?}.",
+                object.name
+            ))
+        }
+    };
+
+    let mut attributes = serde_json::Map::new();
+    let (min, max) = bounds(&positions);
+    attributes.insert(
+        "POSITION".to_string(),
+        json!(buffer.add_floats(&positions, "VEC3", Some((min, max)), true)),
+    );
+
+    if let Some(normals) = object.normals.first() {
+        let normals: Vec<_> = vectors3(&normals.data)
+            .into_iter()
+            .map(|n| glam::Vec3::from_array(n).normalize_or_zero().to_array())
+            .collect();
+        check_count(&object.normals[0].name, normals.len())?;
+        attributes.insert(
+            "NORMAL".to_string(),
+            json!(buffer.add_floats(&normals, "VEC3", None, true)),
+        );
+    }
+
+    for (i, uvs) in object.texture_coordinates.iter().enumerate() {
+        let values = vectors2(&uvs.data);
+        check_count(&uvs.name, values.len())?;
+        attributes.insert(
+            format!("TEXCOORD_{i}"),
+            json!(buffer.add_floats(&values, "VEC2", None, true)),
+        );
+    }
+
+    if let Some(colors) = object.color_sets.first() {
+        let values = vectors4(&colors.data);
+        check_count(&colors.name, values.len())?;
+        attributes.insert(
+            "COLOR_0".to_string(),
+            json!(buffer.add_floats(&values, "VEC4", None, true)),
+        );
+    }
+
+    if !object.bone_influences.is_empty() && !skel.bones.is_empty() {
+        let (joints, weights) = vertex_joints_weights(object, skel, vertex_count, warnings);
+        attributes.insert("JOINTS_0".to_string(), json!(buffer.add_joints(&joints)));
+        attributes.insert(
+            "WEIGHTS_0".to_string(),
+            json!(buffer.add_floats(&weights, "VEC4", None, true)),
+        );
+    }
+
+    if let Some(i) = object
+        .vertex_indices
+        .iter()
+        .find(|i| **i as usize >= vertex_count)
+    {
+        return Err(format!(
+            "Vertex index {i} is out of range for mesh object {:?} with {vertex_count} vertices.",
+            object.name
+        ));
+    }
+
+    let mut primitive = json!({
+        "attributes": attributes,
+        "indices": buffer.add_indices(&object.vertex_indices),
+    });
+    if let Some(material) = material {
+        primitive["material"] = json!(material);
+    }
+    Ok(Some(primitive))
+}
+
+// glTF supports up to 4 influences per vertex for JOINTS_0 and WEIGHTS_0.
+fn vertex_joints_weights(
+    object: &MeshObjectData,
+    skel: &SkelData,
+    vertex_count: usize,
+    warnings: &mut Vec<String>,
+) -> (Vec<[u16; 4]>, Vec<[f32; 4]>) {
+    let mut influences: Vec<Vec<(u16, f32)>> = vec![Vec::new(); vertex_count];
+    for influence in &object.bone_influences {
+        let Some(bone_index) = skel
+            .bones
+            .iter()
+            .position(|b| b.name == influence.bone_name)
+        else {
+            continue;
+        };
+        for weight in &influence.vertex_weights {
+            if let Some(vertex) = influences.get_mut(weight.vertex_index as usize) {
+                vertex.push((bone_index as u16, weight.vertex_weight));
+            }
+        }
+    }
+
+    // All zero weights are invalid, so use the parent bone like unskinned objects.
+    let fallback = skel
+        .bones
+        .iter()
+        .position(|b| b.name == object.parent_bone_name)
+        .unwrap_or_default();
+    let mut fallback_count = 0;
+
+    let (joints, weights) = influences
+        .into_iter()
+        .map(|mut vertex| {
+            vertex.sort_by(|a, b| b.1.total_cmp(&a.1));
+            vertex.truncate(4);
+
+            let total: f32 = vertex.iter().map(|(_, w)| w).sum();
+            let mut joints = [0; 4];
+            let mut weights = [0.0; 4];
+            if total > 0.0 {
+                for (i, (joint, weight)) in vertex.into_iter().enumerate() {
+                    joints[i] = joint;
+                    weights[i] = weight / total;
+                }
+            } else {
+                joints[0] = fallback as u16;
+                weights[0] = 1.0;
+                fallback_count += 1;
+            }
+            (joints, weights)
+        })
+        .unzip();
+
+    if fallback_count > 0 {
+        warnings.push(format!(
+            "Mesh object {:?} has {fallback_count} vertices without weights for bones in the skel. These vertices are fully weighted to {:?}.",
+            object.name, skel.bones[fallback].name
+        ));
+    }
+    (joints, weights)
+}
+
+fn create_animation(buffer: &mut BufferBuilder, anim: &AnimData, skel: &SkelData) -> Option<Value> {
+    let frame_count = anim_frame_count(anim);
+    let times: Vec<[f32; 1]> = (0..frame_count)
+        .map(|i| [i as f32 / FRAMES_PER_SECOND])
+        .collect();
+    let (min, max) = bounds(&times);
+
+    let mut samplers = Vec::new();
+    let mut channels = Vec::new();
+    let mut times_accessor = None;
+    for group in anim
+        .groups
+        .iter()
+        .filter(|g| matches!(g.group_type, GroupType::Transform))
+    {
+        for node in &group.nodes {
+            let Some(bone_index) = skel.bones.iter().position(|b| b.name == node.name) else {
+                continue;
+            };
+            let Some(track) = node
+                .tracks
+                .iter()
+                .find(|t| matches!(t.values, TrackValues::Transform(_)))
+            else {
+                continue;
+            };
+
+            let transforms: Vec<_> = (0..frame_count)
+                .filter_map(|i| match sample_track(&track.values, i as f32) {
+                    Some(TrackValue::Transform(t)) => Some(t),
+                    _ => None,
+                })
+                .collect();
+            if transforms.len() != frame_count {
+                continue;
+            }
+
+            let input = *times_accessor.get_or_insert_with(|| {
+                buffer.add_floats(&times, "SCALAR", Some((min.clone(), max.clone())), false)
+            });
+            let translations: Vec<_> = transforms
+                .iter()
+                .map(|t| [t.translation.x, t.translation.y, t.translation.z])
+                .collect();
+            let rotations: Vec<_> = transforms
+                .iter()
+                .map(|t| {
+                    crate::anim_sampling::quat(&t.rotation)
+                        .normalize()
+                        .to_array()
+                })
+                .collect();
+            let scales: Vec<_> = transforms
+                .iter()
+                .map(|t| [t.scale.x, t.scale.y, t.scale.z])
+                .collect();
+
+            for (path, output) in [
+                (
+                    "translation",
+                    buffer.add_floats(&translations, "VEC3", None, false),
+                ),
+                (
+                    "rotation",
+                    buffer.add_floats(&rotations, "VEC4", None, false),
+                ),
+                ("scale", buffer.add_floats(&scales, "VEC3", None, false)),
+            ] {
+                samplers
+                    .push(json!({ "input": input, "output": output, "interpolation": "LINEAR" }));
+                channels.push(json!({
+                    "sampler": samplers.len() - 1,
+                    "target": { "node": bone_index, "path": path },
+                }));
+            }
+        }
+    }
+
+    (!channels.is_empty()).then(|| json!({ "channels": channels, "samplers": samplers }))
+}
+
+#[derive(Default)]
+struct MaterialBuilder {
+    materials: Vec<Value>,
+    textures: Vec<Value>,
+    images: Vec<Value>,
+    material_indices: BTreeMap<String, usize>,
+    image_indices: BTreeMap<String, usize>,
+}
+
+impl MaterialBuilder {
+    fn add(&mut self, entry: &MatlEntryData) -> usize {
+        if let Some(index) = self.material_indices.get(&entry.material_label) {
+            return *index;
+        }
+
+        let mut material = json!({
+            "name": entry.material_label,
+            "pbrMetallicRoughness": { "metallicFactor": 0.0, "roughnessFactor": 1.0 },
+        });
+
+        // Convert the most common textures to their closest glTF equivalent.
+        if let Some(texture) = self.texture(entry, ParamId::Texture0) {
+            material["pbrMetallicRoughness"]["baseColorTexture"] = json!({ "index": texture });
+        }
+        if let Some(texture) = self.texture(entry, ParamId::Texture4) {
+            material["normalTexture"] = json!({ "index": texture });
+        }
+        if let Some(texture) = self.texture(entry, ParamId::Texture5) {
+            material["emissiveTexture"] = json!({ "index": texture });
+            material["emissiveFactor"] = json!([1.0, 1.0, 1.0]);
+        }
+
+        if entry
+            .blend_states
+            .iter()
+            .any(|b| matches!(b.data.destination_color, BlendFactor::OneMinusSourceAlpha))
+        {
+            material["alphaMode"] = json!("BLEND");
+        }
+        if entry
+            .rasterizer_states
+            .iter()
+            .any(|r| matches!(r.data.cull_mode, CullMode::Disabled))
+        {
+            material["doubleSided"] = json!(true);
+        }
+
+        self.materials.push(material);
+        let index = self.materials.len() - 1;
+        self.material_indices
+            .insert(entry.material_label.clone(), index);
+        index
+    }
+
+    fn texture(&mut self, entry: &MatlEntryData, param_id: ParamId) -> Option<usize> {
+        let name = &entry
+            .textures
+            .iter()
+            .find(|t| t.param_id as u64 == param_id as u64)?
+            .data;
+        let file_name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+
+        let image = match self.image_indices.get(file_name) {
+            Some(index) => *index,
+            None => {
+                self.images
+                    .push(json!({ "uri": format!("{file_name}.png") }));
+                self.textures
+                    .push(json!({ "source": self.images.len() - 1, "sampler": 0 }));
+                self.image_indices
+                    .insert(file_name.to_string(), self.images.len() - 1);
+                self.images.len() - 1
+            }
+        };
+        // Each image has exactly one texture.
+        Some(image)
+    }
+}
+
+#[derive(Default)]
+struct BufferBuilder {
+    data: Vec<u8>,
+    views: Vec<Value>,
+    accessors: Vec<Value>,
+}
+
+impl BufferBuilder {
+    fn add_floats<const N: usize>(
+        &mut self,
+        values: &[[f32; N]],
+        type_: &str,
+        min_max: Option<(Vec<f32>, Vec<f32>)>,
+        is_vertex_data: bool,
+    ) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flatten()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+        let target = is_vertex_data.then_some(ARRAY_BUFFER);
+        let mut accessor = self.add_accessor(&bytes, values.len(), FLOAT, type_, target);
+        if let Some((min, max)) = min_max {
+            accessor["min"] = json!(min);
+            accessor["max"] = json!(max);
+        }
+        self.push_accessor(accessor)
+    }
+
+    fn add_joints(&mut self, values: &[[u16; 4]]) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flatten()
+            .flat_map(|i| i.to_le_bytes())
+            .collect();
+        let accessor = self.add_accessor(
+            &bytes,
+            values.len(),
+            UNSIGNED_SHORT,
+            "VEC4",
+            Some(ARRAY_BUFFER),
+        );
+        self.push_accessor(accessor)
+    }
+
+    fn add_indices(&mut self, values: &[u32]) -> usize {
+        let bytes: Vec<u8> = values.iter().flat_map(|i| i.to_le_bytes()).collect();
+        let accessor = self.add_accessor(
+            &bytes,
+            values.len(),
+            UNSIGNED_INT,
+            "SCALAR",
+            Some(ELEMENT_ARRAY_BUFFER),
+        );
+        self.push_accessor(accessor)
+    }
+
+    fn add_accessor(
+        &mut self,
+        bytes: &[u8],
+        count: usize,
+        component_type: u32,
+        type_: &str,
+        target: Option<u32>,
+    ) -> Value {
+        // All component types used here are at most 4 bytes.
+        while !self.data.len().is_multiple_of(4) {
+            self.data.push(0);
+        }
+
+        let mut view = json!({
+            "buffer": 0,
+            "byteOffset": self.data.len(),
+            "byteLength": bytes.len(),
+        });
+        if let Some(target) = target {
+            view["target"] = json!(target);
+        }
+        self.data.extend_from_slice(bytes);
+        self.views.push(view);
+
+        json!({
+            "bufferView": self.views.len() - 1,
+            "componentType": component_type,
+            "count": count,
+            "type": type_,
+        })
+    }
+
+    fn push_accessor(&mut self, accessor: Value) -> usize {
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+}
+
+fn glb_bytes(mut json: Vec<u8>, mut bin: Vec<u8>) -> Vec<u8> {
+    // Chunks must be aligned to 4 bytes.
+    while !json.len().is_multiple_of(4) {
+        json.push(b' ');
+    }
+    while !bin.len().is_multiple_of(4) {
+        bin.push(0);
+    }
+
+    let length = 12 + 8 + json.len() + 8 + bin.len();
+    let mut bytes = Vec::with_capacity(length);
+    bytes.extend_from_slice(b"glTF");
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    bytes.extend_from_slice(&(length as u32).to_le_bytes());
+
+    bytes.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"JSON");
+    bytes.extend_from_slice(&json);
+
+    bytes.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"BIN\0");
+    bytes.extend_from_slice(&bin);
+    bytes
+}
+
+fn bounds<const N: usize>(values: &[[f32; N]]) -> (Vec<f32>, Vec<f32>) {
+    let mut min = vec![0.0; N];
+    let mut max = vec![0.0; N];
+    if let Some(first) = values.first() {
+        min = first.to_vec();
+        max = first.to_vec();
+    }
+    for value in values {
+        for i in 0..N {
+            min[i] = min[i].min(value[i]);
+            max[i] = max[i].max(value[i]);
+        }
+    }
+    (min, max)
+}
@@ -0,0 +1,587 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use ::gltf::animation::util::ReadOutputs;
+use ::gltf::animation::{Interpolation, Property};
+use ::gltf::mesh::{Mode, Semantic};
+use ::gltf::{Document, Node, Primitive};
+use glam::{Mat4, Quat, Vec3, Vec4};
+use ssbh_data::anim_data::{
+    AnimData, GroupData, GroupType, NodeData, TrackData, TrackValues, TransformFlags,
+};
+use ssbh_data::mesh_data::{
+    AttributeData, BoneInfluence, MeshData, MeshObjectData, VectorData, VertexWeight,
+};
+use ssbh_data::skel_data::{BillboardType, BoneData, SkelData};
+
+use crate::anim_sampling::transform;
+use crate::mesh_attributes::{COLOR_SET_NAMES, UV_SET_NAMES};
+use crate::mesh_transform::transform_object;
+
+// Smash Ultimate animations play at 60 frames per second.
+const FRAMES_PER_SECOND: f32 = 60.0;
+
+/// The data read from a glTF file and a message for each part of the file that was not imported.
+pub struct GltfImport {
+    pub mesh: MeshData,
+    pub skel: SkelData,
+    pub anim: AnimData,
+    pub dropped: Vec<String>,
+}
+
+/// Reads the meshes, skeleton, and first animation from a .gltf or .glb file at `path`.
+///
+/// Bones are created from the joints of the first skin.
+/// The inverse bind matrices are assumed to match the joint transforms, and mismatches are reported.
+/// Mesh objects without a skin use the closest joint in their parent nodes as the parent bone
+/// and keep vertices relative to that joint.
+/// Mesh objects without a skin or parent joint are transformed to model space.
+pub fn read_gltf(path: &Path) -> Result<GltfImport, String> {
+    let ::gltf::Gltf { document, blob } = ::gltf::Gltf::open(path).map_err(|e| format!("{e}"))?;
+    let buffers =
+        ::gltf::import_buffers(&document, path.parent(), blob).map_err(|e| format!("{e}"))?;
+    let buffers: Vec<&[u8]> = buffers.iter().map(|b| b.0.as_slice()).collect();
+    Ok(import_document(&document, &buffers))
+}
+
+fn import_document(document: &Document, buffers: &[&[u8]]) -> GltfImport {
+    let mut dropped = Vec::new();
+
+    let hierarchy = NodeHierarchy::new(document);
+
+    let skins: Vec<_> = document.skins().collect();
+    if skins.len() > 1 {
+        dropped.push(format!(
+            "Only the first of {} skins was imported.",
+            skins.len()
+        ));
+    }
+    let joints: Vec<usize> = skins
+        .first()
+        .map(|s| s.joints().map(|j| j.index()).collect())
+        .unwrap_or_default();
+    let joint_names: Vec<String> = joints.iter().map(|j| hierarchy.names[*j].clone()).collect();
+
+    // Skinned vertices are stored in model space using the rest pose of the skel.
+    if let Some(skin) = skins.first() {
+        let reader = skin.reader(|b| buffers.get(b.index()).copied());
+        if let Some(inverse_bind_matrices) = reader.read_inverse_bind_matrices() {
+            let mismatched: Vec<_> = inverse_bind_matrices
+                .zip(&joints)
+                .filter(|(m, j)| {
+                    !Mat4::from_cols_array_2d(m)
+                        .abs_diff_eq(hierarchy.world_transforms[**j].inverse(), 1e-4)
+                })
+                .map(|(_, j)| hierarchy.names[*j].as_str())
+                .collect();
+            if !mismatched.is_empty() {
+                dropped.push(format!(
+                    "Inverse bind matrices for joints {mismatched:?} do not match the joint transforms and were not imported."
+                ));
+            }
+        }
+    }
+
+    let skel = SkelData {
+        major_version: 1,
+        minor_version: 0,
+        bones: joints
+            .iter()
+            .zip(&joint_names)
+            .map(|(node, name)| BoneData {
+                name: name.clone(),
+                transform: hierarchy
+                    .joint_relative_transform(*node, &joints)
+                    .to_cols_array_2d(),
+                parent_index: hierarchy
+                    .joint_ancestor(*node, &joints)
+                    .and_then(|p| joints.iter().position(|j| *j == p)),
+                billboard_type: BillboardType::Disabled,
+            })
+            .collect(),
+    };
+
+    let mut objects = Vec::new();
+    let mut subindices: HashMap<String, u64> = HashMap::new();
+    for node in document.nodes() {
+        let Some(mesh) = node.mesh() else {
+            continue;
+        };
+        let name = node
+            .name()
+            .or(mesh.name())
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| format!("mesh{}", mesh.index()));
+
+        for primitive in mesh.primitives() {
+            let label = format!("{name:?} primitive {}", primitive.index());
+            let Some(mut object) = import_primitive(
+                &primitive,
+                buffers,
+                &node,
+                &joint_names,
+                &label,
+                &mut dropped,
+            ) else {
+                continue;
+            };
+
+            if node.skin().is_none() {
+                // Vertices for objects without skinning are stored relative to the parent bone.
+                let world = hierarchy.world_transforms[node.index()];
+                match hierarchy.joint_ancestor(node.index(), &joints) {
+                    Some(parent) => {
+                        let parent_world = hierarchy.world_transforms[parent];
+                        transform_object(&mut object, parent_world.inverse() * world);
+                        object.parent_bone_name = hierarchy.names[parent].clone();
+                    }
+                    None => transform_object(&mut object, world),
+                }
+            }
+
+            let subindex = subindices.entry(name.clone()).or_default();
+            object.name = name.clone();
+            object.subindex = *subindex;
+            *subindex += 1;
+            objects.push(object);
+        }
+    }
+
+    if document.materials().len() > 0 {
+        dropped.push(format!(
+            "{} materials were not imported.",
+            document.materials().len()
+        ));
+    }
+
+    let anim = import_animation(document, buffers, &hierarchy, &joints, &mut dropped);
+
+    GltfImport {
+        mesh: MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects,
+        },
+        skel,
+        anim,
+        dropped,
+    }
+}
+
+struct NodeHierarchy {
+    names: Vec<String>,
+    parents: Vec<Option<usize>>,
+    local_transforms: Vec<Mat4>,
+    world_transforms: Vec<Mat4>,
+}
+
+impl NodeHierarchy {
+    fn new(document: &Document) -> Self {
+        let mut parents = vec![None; document.nodes().len()];
+        for node in document.nodes() {
+            for child in node.children() {
+                parents[child.index()] = Some(node.index());
+            }
+        }
+
+        let names = document
+            .nodes()
+            .map(|n| {
+                n.name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| format!("node{}", n.index()))
+            })
+            .collect();
+        let local_transforms: Vec<_> = document
+            .nodes()
+            .map(|n| Mat4::from_cols_array_2d(&n.transform().matrix()))
+            .collect();
+
+        let world_transforms = (0..local_transforms.len())
+            .map(|i| {
+                let mut world = local_transforms[i];
+                let mut parent = parents[i];
+                while let Some(p) = parent {
+                    world = local_transforms[p] * world;
+                    parent = parents[p];
+                }
+                world
+            })
+            .collect();
+
+        Self {
+            names,
+            parents,
+            local_transforms,
+            world_transforms,
+        }
+    }
+
+    // The closest parent node that is also a joint.
+    fn joint_ancestor(&self, node: usize, joints: &[usize]) -> Option<usize> {
+        let mut parent = self.parents[node];
+        while let Some(p) = parent {
+            if joints.contains(&p) {
+                return Some(p);
+            }
+            parent = self.parents[p];
+        }
+        None
+    }
+
+    // Bones store transforms relative to the parent bone,
+    // so include the transforms of any nodes in between that aren't joints.
+    fn joint_relative_transform(&self, node: usize, joints: &[usize]) -> Mat4 {
+        let parent_world = self
+            .joint_ancestor(node, joints)
+            .map(|p| self.world_transforms[p])
+            .unwrap_or(Mat4::IDENTITY);
+        parent_world.inverse() * self.world_transforms[node]
+    }
+}
+
+fn import_primitive(
+    primitive: &Primitive,
+    buffers: &[&[u8]],
+    node: &Node,
+    joint_names: &[String],
+    label: &str,
+    dropped: &mut Vec<String>,
+) -> Option<MeshObjectData> {
+    if primitive.mode() != Mode::Triangles {
+        dropped.push(format!(
+            "Skipped {label} with unsupported mode {:?}.",
+            primitive.mode()
+        ));
+        return None;
+    }
+
+    let reader = primitive.reader(|b| buffers.get(b.index()).copied());
+    let Some(positions) = reader.read_positions() else {
+        dropped.push(format!("Skipped {label} without positions."));
+        return None;
+    };
+    let positions: Vec<[f32; 3]> = positions.collect();
+    let vertex_indices = match reader.read_indices() {
+        Some(indices) => indices.into_u32().collect(),
+        None => (0..positions.len() as u32).collect(),
+    };
+
+    let mut texture_coordinates = Vec::new();
+    let mut color_sets = Vec::new();
+    for attribute in primitive.attributes() {
+        match attribute.0 {
+            Semantic::Positions
+            | Semantic::Normals
+            | Semantic::Tangents
+            | Semantic::Joints(_)
+            | Semantic::Weights(_) => (),
+            Semantic::TexCoords(set) => {
+                match (UV_SET_NAMES.get(set as usize), reader.read_tex_coords(set)) {
+                    (Some(name), Some(values)) => texture_coordinates.push(attribute_data(
+                        name,
+                        VectorData::Vector2(values.into_f32().collect()),
+                    )),
+                    _ => dropped.push(format!("Skipped TEXCOORD_{set} for {label}.")),
+                }
+            }
+            Semantic::Colors(set) => {
+                match (COLOR_SET_NAMES.get(set as usize), reader.read_colors(set)) {
+                    (Some(name), Some(values)) => color_sets.push(attribute_data(
+                        name,
+                        VectorData::Vector4(values.into_rgba_f32().collect()),
+                    )),
+                    _ => dropped.push(format!("Skipped COLOR_{set} for {label}.")),
+                }
+            }
+            Semantic::Extras(name) => {
+                dropped.push(format!("Skipped custom attribute _{name} for {label}."))
+            }
+        }
+    }
+    if primitive.morph_targets().len() > 0 {
+        dropped.push(format!(
+            "Skipped {} morph targets for {label}.",
+            primitive.morph_targets().len()
+        ));
+    }
+
+    let bone_influences = match node.skin() {
+        Some(skin) if skin.index() == 0 => {
+            // Combine the weights from all sets for each joint.
+            let mut weights: BTreeMap<usize, Vec<VertexWeight>> = BTreeMap::new();
+            let mut set = 0;
+            while let (Some(joints), Some(set_weights)) =
+                (reader.read_joints(set), reader.read_weights(set))
+            {
+                for (vertex_index, (joints, vertex_weights)) in
+                    joints.into_u16().zip(set_weights.into_f32()).enumerate()
+                {
+                    for (joint, weight) in joints.into_iter().zip(vertex_weights) {
+                        if weight > 0.0 {
+                            weights
+                                .entry(joint as usize)
+                                .or_default()
+                                .push(VertexWeight {
+                                    vertex_index: vertex_index as u32,
+                                    vertex_weight: weight,
+                                });
+                        }
+                    }
+                }
+                set += 1;
+            }
+
+            weights
+                .into_iter()
+                .filter_map(|(joint, vertex_weights)| {
+                    let Some(bone_name) = joint_names.get(joint) else {
+                        dropped.push(format!(
+                            "Skipped weights for invalid joint {joint} for {label}."
+                        ));
+                        return None;
+                    };
+                    Some(BoneInfluence {
+                        bone_name: bone_name.clone(),
+                        vertex_weights,
+                    })
+                })
+                .collect()
+        }
+        Some(skin) => {
+            dropped.push(format!(
+                "Skipped weights for {label} using skin {}.",
+                skin.index()
+            ));
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    Some(MeshObjectData {
+        name: String::new(),
+        subindex: 0,
+        parent_bone_name: String::new(),
+        disable_depth_test: false,
+        disable_depth_write: false,
+        sort_bias: 0,
+        vertex_indices,
+        positions: vec![attribute_data("Position0", VectorData::Vector3(positions))],
+        normals: reader
+            .read_normals()
+            .map(|n| vec![attribute_data("Normal0", VectorData::Vector3(n.collect()))])
+            .unwrap_or_default(),
+        binormals: Vec::new(),
+        tangents: reader
+            .read_tangents()
+            .map(|t| vec![attribute_data("Tangent0", VectorData::Vector4(t.collect()))])
+            .unwrap_or_default(),
+        texture_coordinates,
+        color_sets,
+        bone_influences,
+    })
+}
+
+fn attribute_data(name: &str, data: VectorData) -> AttributeData {
+    AttributeData {
+        name: name.to_string(),
+        data,
+    }
+}
+
+fn import_animation(
+    document: &Document,
+    buffers: &[&[u8]],
+    hierarchy: &NodeHierarchy,
+    joints: &[usize],
+    dropped: &mut Vec<String>,
+) -> AnimData {
+    let mut anim = AnimData {
+        major_version: 2,
+        minor_version: 0,
+        groups: Vec::new(),
+        final_frame_index: 0.0,
+    };
+
+    let animations: Vec<_> = document.animations().collect();
+    if animations.len() > 1 {
+        dropped.push(format!(
+            "Only the first of {} animations was imported.",
+            animations.len()
+        ));
+    }
+    let Some(animation) = animations.first() else {
+        return anim;
+    };
+
+    // Collect the channels for each joint in the same order as the bones.
+    let mut channels: BTreeMap<usize, NodeChannels> = BTreeMap::new();
+    let mut end_time = 0.0f32;
+    for channel in animation.channels() {
+        let target = channel.target();
+        let node = target.node().index();
+        let Some(bone_index) = joints.iter().position(|j| *j == node) else {
+            dropped.push(format!(
+                "Skipped animation channel for node {:?} that is not a joint.",
+                hierarchy.names[node]
+            ));
+            continue;
+        };
+
+        let reader = channel.reader(|b| buffers.get(b.index()).copied());
+        let (Some(times), Some(outputs)) = (reader.read_inputs(), reader.read_outputs()) else {
+            dropped.push(format!(
+                "Skipped animation channel for node {:?} without keyframes.",
+                hierarchy.names[node]
+            ));
+            continue;
+        };
+        let times: Vec<f32> = times.collect();
+        let values: Vec<Vec4> = match outputs {
+            ReadOutputs::Translations(t) => t.map(|v| Vec3::from_array(v).extend(0.0)).collect(),
+            ReadOutputs::Rotations(r) => r.into_f32().map(Vec4::from_array).collect(),
+            ReadOutputs::Scales(s) => s.map(|v| Vec3::from_array(v).extend(0.0)).collect(),
+            ReadOutputs::MorphTargetWeights(_) => {
+                dropped.push(format!(
+                    "Skipped morph target weights for node {:?}.",
+                    hierarchy.names[node]
+                ));
+                continue;
+            }
+        };
+
+        let interpolation = channel.sampler().interpolation();
+        let values = if interpolation == Interpolation::CubicSpline {
+            dropped.push(format!(
+                "Cubic spline tangents for node {:?} were converted to linear interpolation.",
+                hierarchy.names[node]
+            ));
+            // Each keyframe stores an in tangent, value, and out tangent.
+            values.chunks_exact(3).map(|v| v[1]).collect()
+        } else {
+            values
+        };
+        if times.is_empty() || values.is_empty() {
+            dropped.push(format!(
+                "Skipped animation channel for node {:?} without keyframes.",
+                hierarchy.names[node]
+            ));
+            continue;
+        }
+
+        end_time = end_time.max(times.last().copied().unwrap_or_default());
+        let sampler = Sampler {
+            times,
+            values,
+            step: interpolation == Interpolation::Step,
+        };
+        let node_channels = channels.entry(bone_index).or_default();
+        match target.property() {
+            Property::Translation => node_channels.translation = Some(sampler),
+            Property::Rotation => node_channels.rotation = Some(sampler),
+            Property::Scale => node_channels.scale = Some(sampler),
+            Property::MorphTargetWeights => (),
+        }
+    }
+
+    let frame_count = (end_time * FRAMES_PER_SECOND).round() as usize + 1;
+    let nodes: Vec<_> = channels
+        .into_iter()
+        .map(|(bone_index, channels)| {
+            let node = joints[bone_index];
+            let (rest_scale, rest_rotation, rest_translation) =
+                hierarchy.local_transforms[node].to_scale_rotation_translation();
+
+            // Include the transforms for nodes between the joint and its parent joint.
+            let offset = hierarchy.joint_relative_transform(node, joints)
+                * hierarchy.local_transforms[node].inverse();
+
+            let transforms = (0..frame_count)
+                .map(|frame| {
+                    let time = frame as f32 / FRAMES_PER_SECOND;
+                    let scale = channels
+                        .scale
+                        .as_ref()
+                        .map(|s| s.sample(time, false).truncate())
+                        .unwrap_or(rest_scale);
+                    let rotation = channels
+                        .rotation
+                        .as_ref()
+                        .map(|s| Quat::from_vec4(s.sample(time, true)).normalize())
+                        .unwrap_or(rest_rotation);
+                    let translation = channels
+                        .translation
+                        .as_ref()
+                        .map(|s| s.sample(time, false).truncate())
+                        .unwrap_or(rest_translation);
+
+                    let (scale, rotation, translation) = (offset
+                        * Mat4::from_scale_rotation_translation(scale, rotation, translation))
+                    .to_scale_rotation_translation();
+                    transform(scale, rotation, translation)
+                })
+                .collect();
+
+            NodeData {
+                name: hierarchy.names[node].clone(),
+                tracks: vec![TrackData {
+                    name: "Transform".to_string(),
+                    compensate_scale: false,
+                    transform_flags: TransformFlags {
+                        override_translation: false,
+                        override_rotation: false,
+                        override_scale: false,
+                        override_compensate_scale: false,
+                    },
+                    values: TrackValues::Transform(transforms),
+                }],
+            }
+        })
+        .collect();
+
+    if !nodes.is_empty() {
+        anim.groups.push(GroupData {
+            group_type: GroupType::Transform,
+            nodes,
+        });
+        anim.final_frame_index = (frame_count - 1) as f32;
+    }
+    anim
+}
+
+#[derive(Default)]
+struct NodeChannels {
+    translation: Option<Sampler>,
+    rotation: Option<Sampler>,
+    scale: Option<Sampler>,
+}
+
+// Samplers always have at least one keyframe.
+struct Sampler {
+    times: Vec<f32>,
+    values: Vec<Vec4>,
+    step: bool,
+}
+
+impl Sampler {
+    fn sample(&self, time: f32, is_rotation: bool) -> Vec4 {
+        let count = self.times.len().min(self.values.len());
+        let next = self.times[..count].partition_point(|t| *t <= time);
+        if next == 0 {
+            return self.values[0];
+        }
+        if next == count || self.step {
+            return self.values[next - 1];
+        }
+
+        let (a, b) = (next - 1, next);
+        let factor = (time - self.times[a]) / (self.times[b] - self.times[a]);
+        if is_rotation {
+            Quat::from_vec4(self.values[a])
+                .slerp(Quat::from_vec4(self.values[b]), factor)
+                .into()
+        } else {
+            self.values[a].lerp(self.values[b], factor)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // External crates won't depend on ssbh_data_py, so just make everything public for convenience.
 pub mod adj_data;
 pub mod anim_data;
+pub mod gltf;
 pub mod hlpb_data;
 pub mod matl_data;
 pub mod mesh_data;
@@ -8,6 +9,33 @@ pub mod meshex_data;
 pub mod modl_data;
 pub mod skel_data;
 
+mod adj_update;
+mod anim_blend;
+mod anim_camera;
+mod anim_material;
+mod anim_reduce;
+mod anim_sampling;
+mod anim_visibility;
+mod gltf_export;
+mod gltf_import;
+mod mesh_attributes;
+mod mesh_bounding;
+mod mesh_cleanup;
+mod mesh_colors;
+mod mesh_mirror;
+mod mesh_normals;
+mod mesh_optimize;
+mod mesh_split;
+mod mesh_tangents;
+mod mesh_transform;
+mod mesh_uv;
+mod meshex_update;
+mod obj;
+mod skin_weights;
+mod smd;
+mod vector_data;
+mod version_convert;
+
 mod pyi;
 use map_py::{MapPy, TypedList};
 pub use pyi::*;
@@ -518,6 +518,21 @@ pub mod matl_data {
                 .write_to_file(path)
                 .map_err(|e| MatlDataError::new_err(format!("{e}")))
         }
+
+        fn convert_version(
+            &mut self,
+            py: Python,
+            major_version: u16,
+            minor_version: u16,
+        ) -> PyResult<Vec<String>> {
+            let data = self.clone().map_py(py)?;
+            let changes =
+                crate::version_convert::convert_version(&data, (major_version, minor_version))
+                    .map_err(MatlDataError::new_err)?;
+            self.major_version = major_version;
+            self.minor_version = minor_version;
+            Ok(changes)
+        }
     }
 
     impl PyiMethods for MatlData {
@@ -528,7 +543,13 @@ pub mod matl_data {
         minor_version: int = 6,
     ) -> None: ...
     
-    def save(self, path: str) -> None: ..."#
+    def save(self, path: str) -> None: ...
+
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ..."#
                 .to_string()
         }
     }
@@ -0,0 +1,131 @@
+// Attribute names in the order used for each set index.
+pub const UV_SET_NAMES: [&str; 5] = ["map1", "uvSet", "uvSet1", "uvSet2", "bake1"];
+pub const COLOR_SET_NAMES: [&str; 10] = [
+    "colorSet1",
+    "colorSet2",
+    "colorSet2_1",
+    "colorSet2_2",
+    "colorSet2_3",
+    "colorSet3",
+    "colorSet4",
+    "colorSet5",
+    "colorSet6",
+    "colorSet7",
+];
+
+/// The attribute names and component counts expected by the game for an attribute list of a mesh object.
+pub struct AttributeSchema {
+    /// The name of the attribute list like "positions" or "texture_coordinates".
+    pub list: &'static str,
+    pub names: &'static [&'static str],
+    pub component_counts: &'static [usize],
+}
+
+pub const ATTRIBUTE_SCHEMAS: [AttributeSchema; 6] = [
+    AttributeSchema {
+        list: "positions",
+        names: &["Position0"],
+        component_counts: &[3],
+    },
+    AttributeSchema {
+        list: "normals",
+        names: &["Normal0"],
+        component_counts: &[3, 4],
+    },
+    AttributeSchema {
+        list: "binormals",
+        names: &["Binormal0"],
+        component_counts: &[3],
+    },
+    AttributeSchema {
+        list: "tangents",
+        names: &["Tangent0"],
+        component_counts: &[4],
+    },
+    AttributeSchema {
+        list: "texture_coordinates",
+        names: &UV_SET_NAMES,
+        component_counts: &[2],
+    },
+    AttributeSchema {
+        list: "color_sets",
+        names: &COLOR_SET_NAMES,
+        component_counts: &[4],
+    },
+];
+
+/// Describes the attribute `name` and its mesh object, if known, for error messages.
+pub fn attribute_description(name: &str, object_name: Option<&str>) -> String {
+    match object_name {
+        Some(object_name) => format!("Attribute {name:?} for mesh object {object_name:?}"),
+        None => format!("Attribute {name:?}"),
+    }
+}
+
+/// Checks that an attribute array with `components` columns can be saved for any attribute list.
+pub fn check_array_components(
+    object_name: Option<&str>,
+    name: &str,
+    components: usize,
+) -> Result<(), String> {
+    if (2..=4).contains(&components) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} has {components} components but expected 2, 3, or 4.",
+            attribute_description(name, object_name)
+        ))
+    }
+}
+
+/// Finds the schema for the attribute list that uses the attribute named `name`.
+pub fn schema_for_name(object_name: &str, name: &str) -> Result<&'static AttributeSchema, String> {
+    ATTRIBUTE_SCHEMAS
+        .iter()
+        .find(|s| s.names.contains(&name))
+        .ok_or_else(|| {
+            let names: Vec<_> = ATTRIBUTE_SCHEMAS.iter().flat_map(|s| s.names).collect();
+            format!(
+                "{} is not a known attribute name. Expected one of {names:?}.",
+                attribute_description(name, Some(object_name))
+            )
+        })
+}
+
+/// Checks the name and `[vertex count, component count]` shape of an attribute in the list for `schema`.
+/// The vertex count is only checked if `vertex_count` is set.
+pub fn check_attribute(
+    object_name: &str,
+    schema: &AttributeSchema,
+    name: &str,
+    shape: [usize; 2],
+    vertex_count: Option<usize>,
+) -> Result<(), String> {
+    let description = attribute_description(name, Some(object_name));
+    if !schema.names.contains(&name) {
+        return Err(format!(
+            "{description} is not a known name for {}. Expected one of {:?}.",
+            schema.list, schema.names
+        ));
+    }
+
+    let [count, components] = shape;
+    if !schema.component_counts.contains(&components) {
+        let expected: Vec<_> = schema
+            .component_counts
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        return Err(format!(
+            "{description} has {components} components but expected {}.",
+            expected.join(" or ")
+        ));
+    }
+
+    if let Some(vertex_count) = vertex_count.filter(|c| *c != count) {
+        return Err(format!(
+            "{description} has {count} values but expected {vertex_count}."
+        ));
+    }
+    Ok(())
+}
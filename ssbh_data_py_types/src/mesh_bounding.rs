@@ -0,0 +1,69 @@
+use glam::{Mat3, Vec3};
+use ssbh_data::mesh_data::MeshObjectData;
+use ssbh_data::skel_data::SkelData;
+
+use crate::mesh_transform::parent_bone_to_model_space;
+use crate::vector_data::vectors3;
+
+/// Collects the first position attribute for each object in `objects`.
+///
+/// If `skel` is set, objects with a parent bone are transformed to model space
+/// to match where the object appears in game.
+/// Skinned objects with bone influences ignore `skel` and always use the positions as is.
+/// Otherwise the positions are used as is like when saving the mesh.
+pub fn bounding_points(
+    objects: &[&MeshObjectData],
+    skel: Option<&SkelData>,
+) -> Result<Vec<Vec3>, String> {
+    let mut points = Vec::new();
+    for object in objects {
+        let positions = match skel {
+            Some(skel)
+                if object.bone_influences.is_empty() && !object.parent_bone_name.is_empty() =>
+            {
+                let mut object = (*object).clone();
+                parent_bone_to_model_space(&mut object, skel)?;
+                object.positions.first().map(|p| vectors3(&p.data))
+            }
+            _ => object.positions.first().map(|p| vectors3(&p.data)),
+        };
+        points.extend(positions.unwrap_or_default().into_iter().map(Vec3::from));
+    }
+    Ok(points)
+}
+
+/// Calculates a sphere centered at the average of `points` that contains all the points.
+/// This is the same approach used by ssbh_data for the bounding data when saving.
+pub fn bounding_sphere(points: &[Vec3]) -> (Vec3, f32) {
+    if points.is_empty() {
+        return (Vec3::ZERO, 0.0);
+    }
+
+    let center = points.iter().sum::<Vec3>() / points.len() as f32;
+    let radius_squared = points
+        .iter()
+        .map(|p| p.distance_squared(center))
+        .fold(0.0, f32::max);
+    (center, radius_squared.sqrt())
+}
+
+/// Calculates the minimum and maximum of `points` for each axis.
+pub fn aabb(points: &[Vec3]) -> (Vec3, Vec3) {
+    if points.is_empty() {
+        return (Vec3::ZERO, Vec3::ZERO);
+    }
+
+    points.iter().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    )
+}
+
+/// Calculates the center, rotation, and half extents of a box containing `points`.
+/// This is the same approach used by ssbh_data, which uses the axis-aligned bounding box
+/// with an identity rotation.
+/// The box is not rotated to fit the points, so it may be larger than the smallest oriented box.
+pub fn oriented_bounding_box(points: &[Vec3]) -> (Vec3, Mat3, Vec3) {
+    let (min, max) = aabb(points);
+    ((min + max) / 2.0, Mat3::IDENTITY, (max - min) / 2.0)
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use glam::{IVec3, Vec3};
+use ssbh_data::mesh_data::{MeshObjectData, VertexWeight};
+
+use crate::skin_weights::vertex_count;
+use crate::vector_data::{
+    attributes, attributes_mut, select_vectors, vector_components, vector_count, vectors3,
+};
+
+/// Merges vertices with all attribute values and bone weights within `tolerance`
+/// and returns the number of removed vertices.
+pub fn weld_vertices(object: &mut MeshObjectData, tolerance: f32) -> Result<usize, String> {
+    let count = checked_vertex_count(object)?;
+    let attributes: Vec<_> = attributes(object)
+        .map(|a| vector_components(&a.data))
+        .collect();
+
+    // Sort the weights by influence to make comparisons easier.
+    let mut weights = vec![Vec::new(); count];
+    for (i, influence) in object.bone_influences.iter().enumerate() {
+        for w in &influence.vertex_weights {
+            if let Some(weights) = weights.get_mut(w.vertex_index as usize) {
+                weights.push((i, w.vertex_weight));
+            }
+        }
+    }
+    for w in &mut weights {
+        w.sort_by_key(|(i, _)| *i);
+    }
+
+    let is_close = |a: usize, b: usize| {
+        attributes.iter().all(|(values, n)| {
+            values[a * n..(a + 1) * n]
+                .iter()
+                .zip(&values[b * n..(b + 1) * n])
+                .all(|(x, y)| (x - y).abs() <= tolerance)
+        }) && weights[a].len() == weights[b].len()
+            && weights[a]
+                .iter()
+                .zip(&weights[b])
+                .all(|(x, y)| x.0 == y.0 && (x.1 - y.1).abs() <= tolerance)
+    };
+
+    // Only compare vertices with positions in the same or adjacent grid cells.
+    let positions = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .unwrap_or_else(|| vec![[0.0; 3]; count]);
+    let cell_size = tolerance.max(1e-6);
+    let cell = |i: usize| {
+        (Vec3::from_array(positions[i]) / cell_size)
+            .floor()
+            .as_ivec3()
+    };
+
+    let mut grid: HashMap<IVec3, Vec<usize>> = HashMap::new();
+    let mut kept = Vec::new();
+    let mut remap = vec![0; count];
+    for i in 0..count {
+        let c = cell(i);
+        let existing = neighbor_cells(c)
+            .filter_map(|c| grid.get(&c))
+            .flatten()
+            .find(|j| is_close(i, **j));
+        match existing {
+            Some(j) => remap[i] = remap[*j],
+            None => {
+                remap[i] = kept.len() as u32;
+                kept.push(i);
+                grid.entry(c).or_default().push(i);
+            }
+        }
+    }
+
+    rebuild_vertices(object, &kept, &remap);
+    Ok(count - kept.len())
+}
+
+/// Removes vertices not used by any vertex index and returns the number of removed vertices.
+pub fn remove_unused_vertices(object: &mut MeshObjectData) -> Result<usize, String> {
+    let count = checked_vertex_count(object)?;
+
+    let mut used = vec![false; count];
+    for i in &object.vertex_indices {
+        used[*i as usize] = true;
+    }
+
+    let kept: Vec<_> = (0..count).filter(|i| used[*i]).collect();
+    let mut remap = vec![0; count];
+    for (new, old) in kept.iter().enumerate() {
+        remap[*old] = new as u32;
+    }
+
+    rebuild_vertices(object, &kept, &remap);
+    Ok(count - kept.len())
+}
+
+/// Removes triangles with repeated vertex indices or positions with zero area
+/// and returns the number of removed triangles.
+pub fn remove_degenerate_triangles(object: &mut MeshObjectData) -> Result<usize, String> {
+    checked_vertex_count(object)?;
+    if !object.vertex_indices.len().is_multiple_of(3) {
+        return Err(format!(
+            "Vertex index count {} for mesh object {:?} is not a multiple of 3.",
+            object.vertex_indices.len(),
+            object.name
+        ));
+    }
+
+    let positions = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .unwrap_or_default();
+    let is_degenerate = |face: &[u32]| {
+        let [a, b, c] = [face[0], face[1], face[2]];
+        if a == b || b == c || a == c {
+            return true;
+        }
+        match (
+            positions.get(a as usize),
+            positions.get(b as usize),
+            positions.get(c as usize),
+        ) {
+            (Some(a), Some(b), Some(c)) => {
+                let (a, b, c) = (Vec3::from(*a), Vec3::from(*b), Vec3::from(*c));
+                (b - a).cross(c - a) == Vec3::ZERO
+            }
+            _ => false,
+        }
+    };
+
+    let triangle_count = object.vertex_indices.len() / 3;
+    object.vertex_indices = object
+        .vertex_indices
+        .chunks_exact(3)
+        .filter(|face| !is_degenerate(face))
+        .flatten()
+        .copied()
+        .collect();
+    Ok(triangle_count - object.vertex_indices.len() / 3)
+}
+
+/// Keeps only the vertices in `kept` and updates all attributes, weights, and vertex indices.
+/// The `remap` has the new index for each old vertex index.
+pub fn rebuild_vertices(object: &mut MeshObjectData, kept: &[usize], remap: &[u32]) {
+    for attribute in attributes_mut(object) {
+        attribute.data = select_vectors(&attribute.data, kept);
+    }
+
+    let mut is_kept = vec![false; remap.len()];
+    for i in kept {
+        is_kept[*i] = true;
+    }
+    for influence in &mut object.bone_influences {
+        influence.vertex_weights.retain(|w| {
+            is_kept
+                .get(w.vertex_index as usize)
+                .copied()
+                .unwrap_or_default()
+        });
+        for w in &mut influence.vertex_weights {
+            w.vertex_index = remap[w.vertex_index as usize];
+        }
+    }
+
+    for i in &mut object.vertex_indices {
+        *i = remap[*i as usize];
+    }
+}
+
+/// Adds a copy of each vertex in `sources` with the same attributes and weights to the end of the vertices.
+pub fn append_vertex_copies(object: &mut MeshObjectData, sources: &[usize]) {
+    if sources.is_empty() {
+        return;
+    }
+
+    for attribute in attributes_mut(object) {
+        let count = vector_count(&attribute.data);
+        let indices: Vec<_> = (0..count).chain(sources.iter().copied()).collect();
+        attribute.data = select_vectors(&attribute.data, &indices);
+    }
+
+    let count = vertex_count(object) - sources.len();
+    for influence in &mut object.bone_influences {
+        let weights: HashMap<_, _> = influence
+            .vertex_weights
+            .iter()
+            .map(|w| (w.vertex_index as usize, w.vertex_weight))
+            .collect();
+        for (i, source) in sources.iter().enumerate() {
+            if let Some(vertex_weight) = weights.get(source) {
+                influence.vertex_weights.push(VertexWeight {
+                    vertex_index: (count + i) as u32,
+                    vertex_weight: *vertex_weight,
+                });
+            }
+        }
+    }
+}
+
+/// Checks that all attributes and vertex indices have the same number of vertices and returns the vertex count.
+pub fn checked_vertex_count(object: &MeshObjectData) -> Result<usize, String> {
+    let count = vertex_count(object);
+    if let Some(a) = attributes(object).find(|a| vector_count(&a.data) != count) {
+        return Err(format!(
+            "Attribute {:?} for mesh object {:?} has {} values but expected {count}.",
+            a.name,
+            object.name,
+            vector_count(&a.data)
+        ));
+    }
+    if let Some(i) = object.vertex_indices.iter().find(|i| **i as usize >= count) {
+        return Err(format!(
+            "Vertex index {i} is out of range for mesh object {:?} with {count} vertices.",
+            object.name
+        ));
+    }
+    Ok(count)
+}
+
+fn neighbor_cells(c: IVec3) -> impl Iterator<Item = IVec3> {
+    (-1..=1).flat_map(move |x| {
+        (-1..=1).flat_map(move |y| (-1..=1).map(move |z| c + IVec3::new(x, y, z)))
+    })
+}
@@ -0,0 +1,372 @@
+use glam::Vec3;
+use ssbh_data::mesh_data::{AttributeData, MeshObjectData, VectorData};
+
+use crate::mesh_attributes::{check_attribute, ATTRIBUTE_SCHEMAS};
+use crate::skin_weights::vertex_count;
+use crate::vector_data::{vectors3, vectors4};
+
+/// The value the game multiplies the stored values for the color set named `name` by
+/// or `None` if the scale for the color set is not known.
+pub fn color_set_scale(name: &str) -> Option<f32> {
+    match name {
+        // The stored values are half the displayed values.
+        "colorSet1" => Some(2.0),
+        _ => None,
+    }
+}
+
+/// Returns `scale` if present and the known scale for the color set named `name` otherwise.
+pub fn display_scale(name: &str, scale: Option<f32>) -> Result<f32, String> {
+    scale.or_else(|| color_set_scale(name)).ok_or_else(|| {
+        format!("The scale for color set {name:?} is not known. Specify the scale explicitly.")
+    })
+}
+
+/// The displayed values for the color set named `name` after applying `scale`.
+pub fn display_values(
+    object: &MeshObjectData,
+    name: &str,
+    scale: f32,
+) -> Result<Vec<[f32; 4]>, String> {
+    let attribute = color_set(object, name)?;
+    Ok(vectors4(&attribute.data)
+        .into_iter()
+        .map(|v| v.map(|c| c * scale))
+        .collect())
+}
+
+/// Stores the displayed `values` for the color set named `name` after removing `scale`.
+/// The color set is added if not already present.
+pub fn set_display_values(
+    object: &mut MeshObjectData,
+    name: &str,
+    values: &[[f32; 4]],
+    scale: f32,
+) -> Result<(), String> {
+    let stored = values.iter().map(|v| v.map(|c| c / scale)).collect();
+    set_color_set(object, name, stored)
+}
+
+/// Sets all values for the color set named `name` to the displayed `color` after removing `scale`.
+/// The color set is added if not already present.
+pub fn fill(
+    object: &mut MeshObjectData,
+    name: &str,
+    color: [f32; 4],
+    scale: f32,
+) -> Result<(), String> {
+    let count = vertex_count(object);
+    set_display_values(object, name, &vec![color; count], scale)
+}
+
+/// Calculates the ambient occlusion for the color set named `name` and sets the displayed values
+/// for each channel in `channels` after removing `scale`.
+///
+/// A value of `1.0` is unoccluded, and `0.0` is fully occluded by the triangles of `object`.
+/// Missing color sets use `1.0` for the displayed value of other channels.
+pub fn bake_ambient_occlusion(
+    object: &mut MeshObjectData,
+    name: &str,
+    channels: [bool; 4],
+    sample_count: usize,
+    max_distance: f32,
+    scale: f32,
+) -> Result<(), String> {
+    let occlusion = ambient_occlusion(object, sample_count, max_distance)?;
+    let mut values = match display_values(object, name, scale) {
+        Ok(values) => values,
+        Err(_) => vec![[1.0; 4]; occlusion.len()],
+    };
+    for (value, ao) in values.iter_mut().zip(occlusion) {
+        for (c, channel) in value.iter_mut().zip(channels) {
+            if channel {
+                *c = ao;
+            }
+        }
+    }
+    set_display_values(object, name, &values, scale)
+}
+
+/// Calculates the fraction of rays in the hemisphere around each vertex normal
+/// that don't hit any triangle of `object` within `max_distance`.
+pub fn ambient_occlusion(
+    object: &MeshObjectData,
+    sample_count: usize,
+    max_distance: f32,
+) -> Result<Vec<f32>, String> {
+    let positions: Vec<_> = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .ok_or_else(|| format!("Mesh object {:?} has no positions.", object.name))?
+        .into_iter()
+        .map(Vec3::from)
+        .collect();
+    let normals = vertex_normals(object, &positions);
+
+    let triangles: Vec<_> = object
+        .vertex_indices
+        .chunks_exact(3)
+        .filter_map(|f| {
+            Some([
+                *positions.get(f[0] as usize)?,
+                *positions.get(f[1] as usize)?,
+                *positions.get(f[2] as usize)?,
+            ])
+        })
+        .collect();
+    let bvh = Bvh::new(triangles);
+
+    // Offset the ray origins to avoid hitting the triangles around the vertex.
+    let (min, max) = positions.iter().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    let epsilon = (max - min).length().max(1.0) * 1e-4;
+
+    let directions = hemisphere_samples(sample_count.max(1));
+    Ok(positions
+        .iter()
+        .zip(&normals)
+        .map(|(p, n)| {
+            let (tangent, bitangent) = n.any_orthonormal_pair();
+            let origin = *p + *n * epsilon;
+            let hits = directions
+                .iter()
+                .filter(|d| {
+                    let direction = tangent * d.x + bitangent * d.y + *n * d.z;
+                    bvh.intersects(origin, direction, max_distance)
+                })
+                .count();
+            1.0 - hits as f32 / directions.len() as f32
+        })
+        .collect())
+}
+
+/// Converts a stored color value to an 8-bit value like when saving the mesh.
+pub fn float_to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Converts an 8-bit color value to a stored value like when reading the mesh.
+pub fn u8_to_float(value: u8) -> f32 {
+    value as f32 / 255.0
+}
+
+fn color_set<'a>(object: &'a MeshObjectData, name: &str) -> Result<&'a AttributeData, String> {
+    object
+        .color_sets
+        .iter()
+        .find(|a| a.name == name)
+        .ok_or_else(|| {
+            let names: Vec<_> = object.color_sets.iter().map(|a| a.name.as_str()).collect();
+            format!(
+                "Mesh object {:?} has no color set named {name:?}. Expected one of {names:?}.",
+                object.name
+            )
+        })
+}
+
+fn set_color_set(
+    object: &mut MeshObjectData,
+    name: &str,
+    values: Vec<[f32; 4]>,
+) -> Result<(), String> {
+    let schema = ATTRIBUTE_SCHEMAS
+        .iter()
+        .find(|s| s.list == "color_sets")
+        .unwrap();
+    let count = object.positions.first().map(|_| vertex_count(object));
+    check_attribute(&object.name, schema, name, [values.len(), 4], count)?;
+
+    let data = VectorData::Vector4(values);
+    match object.color_sets.iter_mut().find(|a| a.name == name) {
+        Some(attribute) => attribute.data = data,
+        None => object.color_sets.push(AttributeData {
+            name: name.to_string(),
+            data,
+        }),
+    }
+    Ok(())
+}
+
+fn vertex_normals(object: &MeshObjectData, positions: &[Vec3]) -> Vec<Vec3> {
+    if let Some(normals) = object.normals.first() {
+        return vectors3(&normals.data)
+            .into_iter()
+            .map(|n| Vec3::from(n).normalize_or(Vec3::Z))
+            .collect();
+    }
+
+    // Use the area weighted face normals if the object has no normals.
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+    for f in object.vertex_indices.chunks_exact(3) {
+        let [a, b, c] = [f[0], f[1], f[2]].map(|i| i as usize);
+        if let (Some(pa), Some(pb), Some(pc)) =
+            (positions.get(a), positions.get(b), positions.get(c))
+        {
+            let n = (*pb - *pa).cross(*pc - *pa);
+            normals[a] += n;
+            normals[b] += n;
+            normals[c] += n;
+        }
+    }
+    normals
+        .into_iter()
+        .map(|n| n.normalize_or(Vec3::Z))
+        .collect()
+}
+
+// Cosine weighted directions around +Z using a Fibonacci spiral.
+fn hemisphere_samples(count: usize) -> Vec<Vec3> {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    (0..count)
+        .map(|i| {
+            let r = ((i as f32 + 0.5) / count as f32).sqrt();
+            let theta = i as f32 * golden_angle;
+            Vec3::new(
+                r * theta.cos(),
+                r * theta.sin(),
+                (1.0 - r * r).max(0.0).sqrt(),
+            )
+        })
+        .collect()
+}
+
+// A bounding volume hierarchy for finding ray intersections with many triangles.
+struct Bvh {
+    nodes: Vec<BvhNode>,
+    triangles: Vec<[Vec3; 3]>,
+}
+
+#[derive(Default)]
+struct BvhNode {
+    min: Vec3,
+    max: Vec3,
+    // Leaf nodes have triangles, and other nodes have children at child and child + 1.
+    start: usize,
+    count: usize,
+    child: usize,
+}
+
+impl Bvh {
+    fn new(mut triangles: Vec<[Vec3; 3]>) -> Self {
+        let mut nodes = vec![BvhNode::default()];
+        let count = triangles.len();
+        build_node(&mut nodes, &mut triangles, 0, 0, count);
+        Self { nodes, triangles }
+    }
+
+    fn intersects(&self, origin: Vec3, direction: Vec3, max_distance: f32) -> bool {
+        if self.triangles.is_empty() {
+            return false;
+        }
+
+        let inverse_direction = direction.recip();
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !ray_aabb(origin, inverse_direction, node.min, node.max, max_distance) {
+                continue;
+            }
+            if node.count > 0 {
+                if self.triangles[node.start..node.start + node.count]
+                    .iter()
+                    .any(|t| ray_triangle(origin, direction, t, max_distance))
+                {
+                    return true;
+                }
+            } else {
+                stack.push(node.child);
+                stack.push(node.child + 1);
+            }
+        }
+        false
+    }
+}
+
+fn build_node(
+    nodes: &mut Vec<BvhNode>,
+    triangles: &mut [[Vec3; 3]],
+    index: usize,
+    start: usize,
+    count: usize,
+) {
+    let part = &mut triangles[start..start + count];
+    let (min, max) = part.iter().flatten().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    nodes[index] = BvhNode {
+        min,
+        max,
+        start,
+        count,
+        child: 0,
+    };
+    if count <= 4 {
+        return;
+    }
+
+    // Split at the median centroid along the longest axis.
+    let extent = max - min;
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+    let mid = count / 2;
+    part.select_nth_unstable_by(mid, |a, b| {
+        let a = a[0][axis] + a[1][axis] + a[2][axis];
+        let b = b[0][axis] + b[1][axis] + b[2][axis];
+        a.total_cmp(&b)
+    });
+
+    let child = nodes.len();
+    nodes.push(BvhNode::default());
+    nodes.push(BvhNode::default());
+    nodes[index].count = 0;
+    nodes[index].child = child;
+    build_node(nodes, triangles, child, start, mid);
+    build_node(nodes, triangles, child + 1, start + mid, count - mid);
+}
+
+fn ray_aabb(
+    origin: Vec3,
+    inverse_direction: Vec3,
+    min: Vec3,
+    max: Vec3,
+    max_distance: f32,
+) -> bool {
+    let t0 = (min - origin) * inverse_direction;
+    let t1 = (max - origin) * inverse_direction;
+    let t_min = t0.min(t1).max_element().max(0.0);
+    let t_max = t0.max(t1).min_element().min(max_distance);
+    t_min <= t_max
+}
+
+// Möller–Trumbore ray triangle intersection.
+fn ray_triangle(origin: Vec3, direction: Vec3, [a, b, c]: &[Vec3; 3], max_distance: f32) -> bool {
+    let ab = *b - *a;
+    let ac = *c - *a;
+    let p = direction.cross(ac);
+    let det = ab.dot(p);
+    if det.abs() < 1e-12 {
+        return false;
+    }
+    let inverse_det = 1.0 / det;
+    let s = origin - *a;
+    let u = s.dot(p) * inverse_det;
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+    let q = s.cross(ab);
+    let v = direction.dot(q) * inverse_det;
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+    let t = ac.dot(q) * inverse_det;
+    t > 0.0 && t <= max_distance
+}
@@ -43,6 +43,107 @@ pub mod mesh_data {
                 .map_err(|e| MeshDataError::new_err(format!("{e}")))
         }
 
+        fn convert_version(
+            &mut self,
+            py: Python,
+            major_version: u16,
+            minor_version: u16,
+        ) -> PyResult<Vec<String>> {
+            let version = (major_version, minor_version);
+            // Avoid converting the objects when the version doesn't change.
+            let changes = if version == (self.major_version, self.minor_version) {
+                crate::version_convert::check_version::<ssbh_data::mesh_data::MeshData>(version)
+                    .map(|_| Vec::new())
+            } else {
+                crate::version_convert::convert_version(&self.clone().map_py(py)?, version)
+            }
+            .map_err(MeshDataError::new_err)?;
+            self.major_version = major_version;
+            self.minor_version = minor_version;
+            Ok(changes)
+        }
+
+        #[pyo3(signature = (modl = None))]
+        fn update_subindices(
+            &mut self,
+            py: Python,
+            modl: Option<Py<crate::modl_data::modl_data::ModlData>>,
+        ) -> PyResult<()> {
+            let mut mesh = self.clone().map_py(py)?;
+            match modl {
+                Some(modl) => {
+                    let mut modl_data = modl.borrow(py).clone().map_py(py)?;
+                    crate::mesh_split::update_subindices(&mut mesh, Some(&mut modl_data))
+                        .map_err(MeshDataError::new_err)?;
+                    *modl.borrow_mut(py) = modl_data.map_py(py)?;
+                }
+                None => crate::mesh_split::update_subindices(&mut mesh, None)
+                    .map_err(MeshDataError::new_err)?,
+            }
+            *self = mesh.map_py(py)?;
+            Ok(())
+        }
+
+        fn validate_attributes(&self, py: Python) -> PyResult<()> {
+            for object in self.objects.list.bind(py).iter() {
+                object
+                    .downcast::<MeshObjectData>()?
+                    .borrow()
+                    .validate_attributes(py)?;
+            }
+            Ok(())
+        }
+
+        #[pyo3(signature = (axis = "x", rename_bones = true, skel = None))]
+        fn mirror(
+            &self,
+            py: Python,
+            axis: &str,
+            rename_bones: bool,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<MeshData> {
+            let mut mesh = self.clone().map_py(py)?;
+            let skel = skel.map(|s| s.map_py(py)).transpose()?;
+            for object in &mut mesh.objects {
+                crate::mesh_mirror::mirror_object(object, axis, rename_bones, skel.as_ref())
+                    .map_err(MeshDataError::new_err)?;
+            }
+            mesh.map_py(py)
+        }
+
+        #[pyo3(signature = (skel = None))]
+        fn bounding_sphere(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], f32)> {
+            let points = mesh_bounding_points(py, self, skel)?;
+            let (center, radius) = crate::mesh_bounding::bounding_sphere(&points);
+            Ok((center.to_array(), radius))
+        }
+
+        #[pyo3(signature = (skel = None))]
+        fn aabb(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], [f32; 3])> {
+            let points = mesh_bounding_points(py, self, skel)?;
+            let (min, max) = crate::mesh_bounding::aabb(&points);
+            Ok((min.to_array(), max.to_array()))
+        }
+
+        #[pyo3(signature = (skel = None))]
+        #[allow(clippy::type_complexity)]
+        fn oriented_bounding_box(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], [[f32; 3]; 3], [f32; 3])> {
+            let points = mesh_bounding_points(py, self, skel)?;
+            Ok(obb_values(&points))
+        }
+
         fn __repr__(&self) -> String {
             self.py_repr()
         }
@@ -56,67 +157,1048 @@ pub mod mesh_data {
         minor_version: int = 10,
     ) -> None: ...
 
-    def save(self, path: str) -> None: ..."#
+    def save(self, path: str) -> None: ...
+
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
+    def update_subindices(
+        self,
+        modl: Optional[ModlData] = None,
+    ) -> None: ...
+
+    def validate_attributes(self) -> None: ...
+
+    def mirror(
+        self,
+        axis: str = 'x',
+        rename_bones: bool = True,
+        skel: Optional[SkelData] = None,
+    ) -> MeshData: ...
+
+    def bounding_sphere(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], float]: ...
+
+    def aabb(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[float]]: ...
+
+    def oriented_bounding_box(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[list[float]], list[float]]: ..."#
                 .to_string()
         }
     }
 
     #[pyclass(get_all, set_all)]
-    #[derive(Debug, Clone, MapPy, Pyi, PyRepr, PyInit)]
-    #[map(ssbh_data::mesh_data::MeshObjectData)]
+    #[derive(Debug, Clone, Pyi, PyRepr)]
     #[pyrepr("ssbh_data_py.mesh_data")]
+    #[pyi(has_methods = true)]
     pub struct MeshObjectData {
         pub name: String,
 
         pub subindex: u64,
 
-        #[pyinit(default = "String::new()")]
         #[pyi(default = "''")]
         pub parent_bone_name: String,
 
-        #[pyinit(default = "false")]
         #[pyi(default = "False")]
         pub disable_depth_test: bool,
 
-        #[pyinit(default = "false")]
         #[pyi(default = "False")]
         pub disable_depth_write: bool,
 
-        #[pyinit(default = "0")]
         #[pyi(default = "0")]
         pub sort_bias: i32,
 
-        #[pyinit(default = "numpy::PyArray1::zeros(py, 0, false).into()")]
         #[pyi(default = "numpy.array([])")]
         pub vertex_indices: Py<PyArray1<u32>>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub positions: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub normals: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub binormals: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub tangents: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub texture_coordinates: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub color_sets: TypedList<AttributeData>,
 
-        #[pyinit(default = "TypedList::empty(py)")]
         #[pyi(default = "[]")]
         pub bone_influences: TypedList<BoneInfluence>,
+
+        #[pyi(default = "None")]
+        pub influence_arrays: Option<Py<InfluenceArrays>>,
+    }
+
+    #[pymethods]
+    impl MeshObjectData {
+        #[new]
+        #[pyo3(signature = (
+            name,
+            subindex,
+            parent_bone_name = None,
+            disable_depth_test = None,
+            disable_depth_write = None,
+            sort_bias = None,
+            vertex_indices = None,
+            positions = None,
+            normals = None,
+            binormals = None,
+            tangents = None,
+            texture_coordinates = None,
+            color_sets = None,
+            bone_influences = None,
+            influence_arrays = None
+        ))]
+        #[allow(clippy::too_many_arguments)]
+        fn new(
+            py: Python,
+            name: String,
+            subindex: u64,
+            parent_bone_name: Option<String>,
+            disable_depth_test: Option<bool>,
+            disable_depth_write: Option<bool>,
+            sort_bias: Option<i32>,
+            vertex_indices: Option<Py<PyArray1<u32>>>,
+            positions: Option<TypedList<AttributeData>>,
+            normals: Option<TypedList<AttributeData>>,
+            binormals: Option<TypedList<AttributeData>>,
+            tangents: Option<TypedList<AttributeData>>,
+            texture_coordinates: Option<TypedList<AttributeData>>,
+            color_sets: Option<TypedList<AttributeData>>,
+            bone_influences: Option<TypedList<BoneInfluence>>,
+            influence_arrays: Option<Py<InfluenceArrays>>,
+        ) -> PyResult<Self> {
+            Ok(Self {
+                name,
+                subindex,
+                parent_bone_name: parent_bone_name.unwrap_or_default(),
+                disable_depth_test: disable_depth_test.unwrap_or(false),
+                disable_depth_write: disable_depth_write.unwrap_or(false),
+                sort_bias: sort_bias.unwrap_or(0),
+                vertex_indices: vertex_indices
+                    .unwrap_or_else(|| numpy::PyArray1::zeros(py, 0, false).into()),
+                positions: positions.unwrap_or_else(|| TypedList::empty(py)),
+                normals: normals.unwrap_or_else(|| TypedList::empty(py)),
+                binormals: binormals.unwrap_or_else(|| TypedList::empty(py)),
+                tangents: tangents.unwrap_or_else(|| TypedList::empty(py)),
+                texture_coordinates: texture_coordinates.unwrap_or_else(|| TypedList::empty(py)),
+                color_sets: color_sets.unwrap_or_else(|| TypedList::empty(py)),
+                bone_influences: bone_influences.unwrap_or_else(|| TypedList::empty(py)),
+                influence_arrays,
+            })
+        }
+
+        #[pyo3(signature = (max_influences = None))]
+        #[allow(clippy::type_complexity)]
+        fn weight_arrays(
+            &self,
+            py: Python,
+            max_influences: Option<usize>,
+        ) -> PyResult<(Py<PyArray2<u32>>, Py<PyArray2<f32>>, Vec<String>)> {
+            let vertex_count = self.vertex_count(py)?;
+            let arrays = crate::skin_weights::weight_arrays(
+                &self.influences(py)?,
+                vertex_count,
+                max_influences,
+            )
+            .map_err(MeshDataError::new_err)?;
+            let shape = (vertex_count, arrays.influence_count);
+            Ok((
+                arrays.indices.into_pyarray(py).reshape(shape)?.into(),
+                arrays.weights.into_pyarray(py).reshape(shape)?.into(),
+                arrays.bone_names,
+            ))
+        }
+
+        fn weight_matrix(&self, py: Python) -> PyResult<(Py<PyArray2<f32>>, Vec<String>)> {
+            let vertex_count = self.vertex_count(py)?;
+            let (matrix, bone_names) =
+                crate::skin_weights::weight_matrix(&self.influences(py)?, vertex_count)
+                    .map_err(MeshDataError::new_err)?;
+            let shape = (vertex_count, bone_names.len());
+            Ok((matrix.into_pyarray(py).reshape(shape)?.into(), bone_names))
+        }
+
+        fn set_weight_arrays(
+            &mut self,
+            py: Python,
+            indices: Py<PyArray2<u32>>,
+            weights: Py<PyArray2<f32>>,
+            bone_names: Vec<String>,
+        ) -> PyResult<()> {
+            let (indices, shape) = array2_values(py, &indices);
+            let (weights, weights_shape) = array2_values(py, &weights);
+            if shape != weights_shape {
+                return Err(MeshDataError::new_err(format!(
+                    "Expected the same shape for indices and weights but found {shape:?} and {weights_shape:?}."
+                )));
+            }
+            self.check_row_count(py, shape[0])?;
+            let arrays = InfluenceArrays::from_values(py, indices, weights, shape, bone_names)?;
+            self.set_influence_arrays(py, arrays)
+        }
+
+        fn set_weight_matrix(
+            &mut self,
+            py: Python,
+            weights: Py<PyArray2<f32>>,
+            bone_names: Vec<String>,
+        ) -> PyResult<()> {
+            let (weights, shape) = array2_values(py, &weights);
+            self.check_row_count(py, shape[0])?;
+            let influences =
+                crate::skin_weights::influences_from_matrix(&weights, shape[1], &bone_names)
+                    .map_err(MeshDataError::new_err)?;
+            let arrays = InfluenceArrays::from_influences(py, &influences, shape[0])?;
+            self.set_influence_arrays(py, arrays)
+        }
+
+        fn normalize_weights(&mut self, py: Python) -> PyResult<Py<PyArray1<u32>>> {
+            self.edit_influences(py, |i| crate::skin_weights::normalize_weights(i))?
+                .map_py(py)
+        }
+
+        #[pyo3(signature = (threshold = 0.001))]
+        fn prune_weights(&mut self, py: Python, threshold: f32) -> PyResult<Py<PyArray1<u32>>> {
+            self.edit_influences(py, |i| crate::skin_weights::prune_weights(i, threshold))?
+                .map_py(py)
+        }
+
+        #[pyo3(signature = (max_influences = 4))]
+        fn limit_influences(
+            &mut self,
+            py: Python,
+            max_influences: usize,
+        ) -> PyResult<Py<PyArray1<u32>>> {
+            self.edit_influences(py, |i| {
+                crate::skin_weights::limit_influences(i, max_influences)
+            })?
+            .map_py(py)
+        }
+
+        fn remove_empty_influences(&mut self, py: Python) -> PyResult<Vec<String>> {
+            self.edit_influences(py, crate::skin_weights::remove_empty_influences)
+        }
+
+        #[pyo3(signature = (tolerance = 1e-6))]
+        fn weld_vertices(&mut self, py: Python, tolerance: f32) -> PyResult<usize> {
+            self.edit(py, |o| crate::mesh_cleanup::weld_vertices(o, tolerance))
+        }
+
+        fn remove_unused_vertices(&mut self, py: Python) -> PyResult<usize> {
+            self.edit(py, crate::mesh_cleanup::remove_unused_vertices)
+        }
+
+        fn remove_degenerate_triangles(&mut self, py: Python) -> PyResult<usize> {
+            self.edit(py, crate::mesh_cleanup::remove_degenerate_triangles)
+        }
+
+        fn optimize_vertex_cache(&mut self, py: Python) -> PyResult<()> {
+            self.edit(py, crate::mesh_optimize::optimize_vertex_cache)
+        }
+
+        #[pyo3(signature = (threshold = 1.05))]
+        fn optimize_overdraw(&mut self, py: Python, threshold: f32) -> PyResult<()> {
+            self.edit(py, |o| {
+                crate::mesh_optimize::optimize_overdraw(o, threshold)
+            })
+        }
+
+        fn optimize_vertex_fetch(&mut self, py: Python) -> PyResult<()> {
+            self.edit(py, crate::mesh_optimize::optimize_vertex_fetch)
+        }
+
+        #[pyo3(signature = (target_triangle_count = 0, target_error = 0.01))]
+        fn simplify(
+            &mut self,
+            py: Python,
+            target_triangle_count: usize,
+            target_error: f32,
+        ) -> PyResult<usize> {
+            self.edit(py, |o| {
+                crate::mesh_optimize::simplify(o, target_triangle_count, target_error)
+            })
+        }
+
+        #[pyo3(signature = (angle_threshold = 180.0, weighting = "area", preserve_split_normals = false))]
+        fn recalculate_normals(
+            &mut self,
+            py: Python,
+            angle_threshold: f32,
+            weighting: &str,
+            preserve_split_normals: bool,
+        ) -> PyResult<usize> {
+            let weighting = normal_weighting_rs(weighting)?;
+            self.edit(py, |o| {
+                crate::mesh_normals::recalculate_normals(
+                    o,
+                    angle_threshold,
+                    weighting,
+                    preserve_split_normals,
+                )
+            })
+        }
+
+        #[pyo3(signature = (uv_set = "map1"))]
+        fn generate_tangent_space(&mut self, py: Python, uv_set: &str) -> PyResult<usize> {
+            let result = self.edit(py, |o| {
+                crate::mesh_tangents::generate_tangent_space(o, uv_set)
+            })?;
+            for warning in result.warnings {
+                let message = std::ffi::CString::new(warning)?;
+                PyErr::warn(
+                    py,
+                    &py.get_type::<pyo3::exceptions::PyUserWarning>(),
+                    &message,
+                    1,
+                )?;
+            }
+            Ok(result.added_vertices)
+        }
+
+        #[pyo3(signature = (uv_set = "map1"))]
+        fn flip_uv_v(&mut self, py: Python, uv_set: &str) -> PyResult<()> {
+            self.edit(py, |o| crate::mesh_uv::flip_v(o, uv_set))
+        }
+
+        #[pyo3(signature = (transform, uv_set = "map1"))]
+        fn transform_uvs(
+            &mut self,
+            py: Python,
+            transform: crate::matl_data::matl_data::UvTransform,
+            uv_set: &str,
+        ) -> PyResult<()> {
+            let transform = transform.map_py(py)?;
+            self.edit(py, |o| crate::mesh_uv::transform_uvs(o, uv_set, &transform))
+        }
+
+        fn copy_uv_set(&mut self, py: Python, uv_set: &str, name: &str) -> PyResult<()> {
+            self.edit(py, |o| crate::mesh_uv::copy_uv_set(o, uv_set, name))
+        }
+
+        fn rename_uv_set(&mut self, py: Python, uv_set: &str, name: &str) -> PyResult<()> {
+            self.edit(py, |o| crate::mesh_uv::rename_uv_set(o, uv_set, name))
+        }
+
+        #[pyo3(signature = (uv_set = "map1"))]
+        fn uv_bounds(&self, py: Python, uv_set: &str) -> PyResult<([f32; 2], [f32; 2])> {
+            let object = self.clone().map_py(py)?;
+            let (min, max) =
+                crate::mesh_uv::uv_bounds(&object, uv_set).map_err(MeshDataError::new_err)?;
+            Ok((min.to_array(), max.to_array()))
+        }
+
+        #[pyo3(signature = (uv_set = "map1"))]
+        fn uv_island_count(&self, py: Python, uv_set: &str) -> PyResult<usize> {
+            let object = self.clone().map_py(py)?;
+            crate::mesh_uv::uv_island_count(&object, uv_set).map_err(MeshDataError::new_err)
+        }
+
+        #[pyo3(signature = (name = "colorSet1", scale = None))]
+        fn color_set_display_values(
+            &self,
+            py: Python,
+            name: &str,
+            scale: Option<f32>,
+        ) -> PyResult<Py<PyArray2<f32>>> {
+            let object = self.clone().map_py(py)?;
+            let scale =
+                crate::mesh_colors::display_scale(name, scale).map_err(MeshDataError::new_err)?;
+            let values = crate::mesh_colors::display_values(&object, name, scale)
+                .map_err(MeshDataError::new_err)?;
+            vectors_pyarray(py, &values)
+        }
+
+        #[pyo3(signature = (name, values, scale = None))]
+        fn set_color_set_display_values(
+            &mut self,
+            py: Python,
+            name: &str,
+            values: Py<PyArray2<f32>>,
+            scale: Option<f32>,
+        ) -> PyResult<()> {
+            let values = color_values(py, &self.name, name, &values)?;
+            let scale =
+                crate::mesh_colors::display_scale(name, scale).map_err(MeshDataError::new_err)?;
+            self.edit(py, |o| {
+                crate::mesh_colors::set_display_values(o, name, &values, scale)
+            })
+        }
+
+        #[pyo3(signature = (name, color, scale = None))]
+        fn fill_color_set(
+            &mut self,
+            py: Python,
+            name: &str,
+            color: [f32; 4],
+            scale: Option<f32>,
+        ) -> PyResult<()> {
+            let scale =
+                crate::mesh_colors::display_scale(name, scale).map_err(MeshDataError::new_err)?;
+            self.edit(py, |o| crate::mesh_colors::fill(o, name, color, scale))
+        }
+
+        #[pyo3(signature = (name = "colorSet1", channels = "rgb", sample_count = 64, max_distance = None, scale = None))]
+        fn bake_ambient_occlusion(
+            &mut self,
+            py: Python,
+            name: &str,
+            channels: &str,
+            sample_count: usize,
+            max_distance: Option<f32>,
+            scale: Option<f32>,
+        ) -> PyResult<()> {
+            let channels = color_channels_rs(channels)?;
+            let scale =
+                crate::mesh_colors::display_scale(name, scale).map_err(MeshDataError::new_err)?;
+            self.edit(py, |o| {
+                crate::mesh_colors::bake_ambient_occlusion(
+                    o,
+                    name,
+                    channels,
+                    sample_count,
+                    max_distance.unwrap_or(f32::INFINITY),
+                    scale,
+                )
+            })
+        }
+
+        fn color_set_u8(&self, py: Python, name: &str) -> PyResult<Py<PyArray2<u8>>> {
+            let object = self.clone().map_py(py)?;
+            // Convert the stored values without applying any scale.
+            let values = crate::mesh_colors::display_values(&object, name, 1.0)
+                .map_err(MeshDataError::new_err)?;
+            let count = values.len();
+            Ok(values
+                .into_iter()
+                .flatten()
+                .map(crate::mesh_colors::float_to_u8)
+                .collect::<Vec<_>>()
+                .into_pyarray(py)
+                .reshape((count, 4))?
+                .into())
+        }
+
+        fn set_color_set_u8(
+            &mut self,
+            py: Python,
+            name: &str,
+            values: Py<PyArray2<u8>>,
+        ) -> PyResult<()> {
+            let values: Vec<_> = color_values(py, &self.name, name, &values)?
+                .into_iter()
+                .map(|v| v.map(crate::mesh_colors::u8_to_float))
+                .collect();
+            self.edit(py, |o| {
+                crate::mesh_colors::set_display_values(o, name, &values, 1.0)
+            })
+        }
+
+        #[pyo3(signature = (flip_vectors = true, reverse_winding = true))]
+        fn flip_normals(
+            &mut self,
+            py: Python,
+            flip_vectors: bool,
+            reverse_winding: bool,
+        ) -> PyResult<()> {
+            self.edit(py, |o| {
+                crate::mesh_normals::flip_normals(o, flip_vectors, reverse_winding);
+                Ok(())
+            })
+        }
+
+        #[pyo3(signature = (axis = "x", rename_bones = true, skel = None))]
+        fn mirror(
+            &self,
+            py: Python,
+            axis: &str,
+            rename_bones: bool,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<MeshObjectData> {
+            let mut object = self.clone().map_py(py)?;
+            let skel = skel.map(|s| s.map_py(py)).transpose()?;
+            crate::mesh_mirror::mirror_object(&mut object, axis, rename_bones, skel.as_ref())
+                .map_err(MeshDataError::new_err)?;
+            object.map_py(py)
+        }
+
+        #[pyo3(signature = (skel = None))]
+        fn bounding_sphere(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], f32)> {
+            let points = object_bounding_points(py, self, skel)?;
+            let (center, radius) = crate::mesh_bounding::bounding_sphere(&points);
+            Ok((center.to_array(), radius))
+        }
+
+        #[pyo3(signature = (skel = None))]
+        fn aabb(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], [f32; 3])> {
+            let points = object_bounding_points(py, self, skel)?;
+            let (min, max) = crate::mesh_bounding::aabb(&points);
+            Ok((min.to_array(), max.to_array()))
+        }
+
+        #[pyo3(signature = (skel = None))]
+        #[allow(clippy::type_complexity)]
+        fn oriented_bounding_box(
+            &self,
+            py: Python,
+            skel: Option<crate::skel_data::skel_data::SkelData>,
+        ) -> PyResult<([f32; 3], [[f32; 3]; 3], [f32; 3])> {
+            let points = object_bounding_points(py, self, skel)?;
+            Ok(obb_values(&points))
+        }
+
+        fn add_attribute(
+            &mut self,
+            py: Python,
+            name: &str,
+            data: Bound<'_, PyAny>,
+        ) -> PyResult<()> {
+            let data = attribute_array(py, &data, name, Some(&self.name))?;
+            let schema = crate::mesh_attributes::schema_for_name(&self.name, name)
+                .map_err(MeshDataError::new_err)?;
+
+            // Replacing an attribute can change the vertex count if it's the only attribute.
+            let mut vertex_count = None;
+            for (_, list) in self.attribute_lists() {
+                for item in list.list.bind(py).iter() {
+                    let attribute = item.downcast::<AttributeData>()?.borrow();
+                    if attribute.name != name {
+                        vertex_count.get_or_insert(array2_shape(py, &attribute.data)[0]);
+                    }
+                }
+            }
+            crate::mesh_attributes::check_attribute(
+                &self.name,
+                schema,
+                name,
+                array2_shape(py, &data),
+                vertex_count,
+            )
+            .map_err(MeshDataError::new_err)?;
+
+            let (_, list) = self
+                .attribute_lists()
+                .into_iter()
+                .find(|(l, _)| *l == schema.list)
+                .unwrap();
+            for item in list.list.bind(py).iter() {
+                let mut attribute = item.downcast::<AttributeData>()?.borrow_mut();
+                if attribute.name == name {
+                    attribute.data = data;
+                    return Ok(());
+                }
+            }
+            list.list.bind(py).append(Py::new(
+                py,
+                AttributeData {
+                    name: name.to_string(),
+                    data,
+                },
+            )?)
+        }
+
+        fn validate_attributes(&self, py: Python) -> PyResult<()> {
+            let mut vertex_count = None;
+            for (schema, (_, list)) in crate::mesh_attributes::ATTRIBUTE_SCHEMAS
+                .iter()
+                .zip(self.attribute_lists())
+            {
+                for item in list.list.bind(py).iter() {
+                    let attribute = item.downcast::<AttributeData>()?.borrow();
+                    let shape = array2_shape(py, &attribute.data);
+                    crate::mesh_attributes::check_attribute(
+                        &self.name,
+                        schema,
+                        &attribute.name,
+                        shape,
+                        vertex_count,
+                    )
+                    .map_err(MeshDataError::new_err)?;
+                    vertex_count.get_or_insert(shape[0]);
+                }
+            }
+            Ok(())
+        }
+
+        fn split_by_connectivity(&self, py: Python) -> PyResult<Vec<MeshObjectData>> {
+            let object = self.clone().map_py(py)?;
+            let ids =
+                crate::mesh_split::connected_components(&object).map_err(MeshDataError::new_err)?;
+            crate::mesh_split::split_by_triangle_ids(&object, &ids)
+                .map_err(MeshDataError::new_err)?
+                .into_iter()
+                .map(|o| o.map_py(py))
+                .collect()
+        }
+
+        fn split_by_triangle_ids(
+            &self,
+            py: Python,
+            triangle_ids: Py<PyArray1<u32>>,
+        ) -> PyResult<Vec<MeshObjectData>> {
+            let object = self.clone().map_py(py)?;
+            let ids = triangle_ids.extract::<Vec<u32>>(py)?;
+            crate::mesh_split::split_by_triangle_ids(&object, &ids)
+                .map_err(MeshDataError::new_err)?
+                .into_iter()
+                .map(|o| o.map_py(py))
+                .collect()
+        }
+
+        fn split_by_vertex_count(
+            &self,
+            py: Python,
+            max_vertex_count: usize,
+        ) -> PyResult<Vec<MeshObjectData>> {
+            let object = self.clone().map_py(py)?;
+            let ids = crate::mesh_split::vertex_count_groups(&object, max_vertex_count)
+                .map_err(MeshDataError::new_err)?;
+            crate::mesh_split::split_by_triangle_ids(&object, &ids)
+                .map_err(MeshDataError::new_err)?
+                .into_iter()
+                .map(|o| o.map_py(py))
+                .collect()
+        }
+
+        fn __repr__(&self) -> String {
+            self.py_repr()
+        }
+    }
+
+    impl MeshObjectData {
+        fn edit<T>(
+            &mut self,
+            py: Python,
+            edit: impl FnOnce(&mut ssbh_data::mesh_data::MeshObjectData) -> Result<T, String>,
+        ) -> PyResult<T> {
+            let mut object: ssbh_data::mesh_data::MeshObjectData = self.clone().map_py(py)?;
+            let result = edit(&mut object).map_err(MeshDataError::new_err)?;
+
+            // Keep influences stored as arrays to avoid creating a VertexWeight for each weight.
+            let arrays = match self.influence_arrays {
+                Some(_) => {
+                    let vertex_count = crate::skin_weights::vertex_count(&object);
+                    let influences = std::mem::take(&mut object.bone_influences);
+                    Some(InfluenceArrays::from_influences(
+                        py,
+                        &influences,
+                        vertex_count,
+                    )?)
+                }
+                None => None,
+            };
+            *self = object.map_py(py)?;
+            if let Some(arrays) = arrays {
+                self.influence_arrays = Some(Py::new(py, arrays)?);
+            }
+            Ok(result)
+        }
+
+        fn attribute_lists(&self) -> [(&'static str, &TypedList<AttributeData>); 6] {
+            // The same order as the attribute schemas.
+            [
+                ("positions", &self.positions),
+                ("normals", &self.normals),
+                ("binormals", &self.binormals),
+                ("tangents", &self.tangents),
+                ("texture_coordinates", &self.texture_coordinates),
+                ("color_sets", &self.color_sets),
+            ]
+        }
+
+        fn edit_influences<T>(
+            &mut self,
+            py: Python,
+            edit: impl FnOnce(&mut Vec<ssbh_data::mesh_data::BoneInfluence>) -> T,
+        ) -> PyResult<T> {
+            let mut influences = self.influences(py)?;
+            let result = edit(&mut influences);
+            match self.influence_arrays {
+                Some(_) => {
+                    let vertex_count = self.vertex_count(py)?;
+                    let arrays = InfluenceArrays::from_influences(py, &influences, vertex_count)?;
+                    self.influence_arrays = Some(Py::new(py, arrays)?);
+                }
+                None => self.bone_influences = influences.map_py(py)?,
+            }
+            Ok(result)
+        }
+
+        // Get the influences from either storage without converting the rest of the object.
+        fn influences(&self, py: Python) -> PyResult<Vec<ssbh_data::mesh_data::BoneInfluence>> {
+            match &self.influence_arrays {
+                Some(arrays) => {
+                    if !self.bone_influences.list.bind(py).is_empty() {
+                        return Err(MeshDataError::new_err(format!(
+                            "Mesh object {:?} has values for both bone_influences and influence_arrays.",
+                            self.name
+                        )));
+                    }
+                    arrays.borrow(py).influences(py)
+                }
+                None => self.bone_influences.clone().map_py(py),
+            }
+        }
+
+        fn set_influence_arrays(&mut self, py: Python, arrays: InfluenceArrays) -> PyResult<()> {
+            self.bone_influences = TypedList::empty(py);
+            self.influence_arrays = Some(Py::new(py, arrays)?);
+            Ok(())
+        }
+
+        // The same vertex count as crate::skin_weights::vertex_count.
+        fn vertex_count(&self, py: Python) -> PyResult<usize> {
+            match self.positions.list.bind(py).iter().next() {
+                Some(positions) => {
+                    Ok(array2_shape(py, &positions.downcast::<AttributeData>()?.borrow().data)[0])
+                }
+                None => {
+                    let indices = self.vertex_indices.bind(py).readonly();
+                    Ok(indices
+                        .as_array()
+                        .iter()
+                        .map(|i| *i as usize + 1)
+                        .max()
+                        .unwrap_or_default())
+                }
+            }
+        }
+
+        fn check_row_count(&self, py: Python, row_count: usize) -> PyResult<()> {
+            let vertex_count = self.vertex_count(py)?;
+            if row_count != vertex_count {
+                return Err(MeshDataError::new_err(format!(
+                    "Expected {vertex_count} rows to match the vertex count for mesh object {:?} but found {row_count}.",
+                    self.name
+                )));
+            }
+            Ok(())
+        }
+    }
+
+    impl MapPy<ssbh_data::mesh_data::MeshObjectData> for MeshObjectData {
+        fn map_py(self, py: Python) -> PyResult<ssbh_data::mesh_data::MeshObjectData> {
+            let bone_influences = self.influences(py)?;
+            Ok(ssbh_data::mesh_data::MeshObjectData {
+                name: self.name,
+                subindex: self.subindex,
+                parent_bone_name: self.parent_bone_name,
+                disable_depth_test: self.disable_depth_test,
+                disable_depth_write: self.disable_depth_write,
+                sort_bias: self.sort_bias,
+                vertex_indices: self.vertex_indices.map_py(py)?,
+                positions: self.positions.map_py(py)?,
+                normals: self.normals.map_py(py)?,
+                binormals: self.binormals.map_py(py)?,
+                tangents: self.tangents.map_py(py)?,
+                texture_coordinates: self.texture_coordinates.map_py(py)?,
+                color_sets: self.color_sets.map_py(py)?,
+                bone_influences,
+            })
+        }
+    }
+
+    impl MapPy<MeshObjectData> for ssbh_data::mesh_data::MeshObjectData {
+        fn map_py(self, py: Python) -> PyResult<MeshObjectData> {
+            Ok(MeshObjectData {
+                name: self.name,
+                subindex: self.subindex,
+                parent_bone_name: self.parent_bone_name,
+                disable_depth_test: self.disable_depth_test,
+                disable_depth_write: self.disable_depth_write,
+                sort_bias: self.sort_bias,
+                vertex_indices: self.vertex_indices.map_py(py)?,
+                positions: self.positions.map_py(py)?,
+                normals: self.normals.map_py(py)?,
+                binormals: self.binormals.map_py(py)?,
+                tangents: self.tangents.map_py(py)?,
+                texture_coordinates: self.texture_coordinates.map_py(py)?,
+                color_sets: self.color_sets.map_py(py)?,
+                bone_influences: self.bone_influences.map_py(py)?,
+                influence_arrays: None,
+            })
+        }
+    }
+
+    impl PyiMethods for MeshObjectData {
+        fn pyi_methods() -> String {
+            r#"    def __init__(
+        self,
+        name: str,
+        subindex: int,
+        parent_bone_name: str = '',
+        disable_depth_test: bool = False,
+        disable_depth_write: bool = False,
+        sort_bias: int = 0,
+        vertex_indices: numpy.ndarray = numpy.array([]),
+        positions: list[AttributeData] = [],
+        normals: list[AttributeData] = [],
+        binormals: list[AttributeData] = [],
+        tangents: list[AttributeData] = [],
+        texture_coordinates: list[AttributeData] = [],
+        color_sets: list[AttributeData] = [],
+        bone_influences: list[BoneInfluence] = [],
+        influence_arrays: Optional[InfluenceArrays] = None
+    ) -> None: ...
+
+    def weight_arrays(
+        self,
+        max_influences: Optional[int] = None,
+    ) -> tuple[numpy.ndarray, numpy.ndarray, list[str]]: ...
+
+    def weight_matrix(self) -> tuple[numpy.ndarray, list[str]]: ...
+
+    def set_weight_arrays(
+        self,
+        indices: numpy.ndarray,
+        weights: numpy.ndarray,
+        bone_names: list[str],
+    ) -> None: ...
+
+    def set_weight_matrix(
+        self,
+        weights: numpy.ndarray,
+        bone_names: list[str],
+    ) -> None: ...
+
+    def normalize_weights(self) -> numpy.ndarray: ...
+
+    def prune_weights(self, threshold: float = 0.001) -> numpy.ndarray: ...
+
+    def limit_influences(self, max_influences: int = 4) -> numpy.ndarray: ...
+
+    def remove_empty_influences(self) -> list[str]: ...
+
+    def weld_vertices(self, tolerance: float = 1e-6) -> int: ...
+
+    def remove_unused_vertices(self) -> int: ...
+
+    def remove_degenerate_triangles(self) -> int: ...
+
+    def optimize_vertex_cache(self) -> None: ...
+
+    def optimize_overdraw(self, threshold: float = 1.05) -> None: ...
+
+    def optimize_vertex_fetch(self) -> None: ...
+
+    def simplify(
+        self,
+        target_triangle_count: int = 0,
+        target_error: float = 0.01,
+    ) -> int: ...
+
+    def recalculate_normals(
+        self,
+        angle_threshold: float = 180.0,
+        weighting: str = 'area',
+        preserve_split_normals: bool = False,
+    ) -> int: ...
+
+    def generate_tangent_space(self, uv_set: str = 'map1') -> int: ...
+
+    def flip_uv_v(self, uv_set: str = 'map1') -> None: ...
+
+    def transform_uvs(
+        self,
+        transform: UvTransform,
+        uv_set: str = 'map1',
+    ) -> None: ...
+
+    def copy_uv_set(self, uv_set: str, name: str) -> None: ...
+
+    def rename_uv_set(self, uv_set: str, name: str) -> None: ...
+
+    def uv_bounds(self, uv_set: str = 'map1') -> tuple[list[float], list[float]]: ...
+
+    def uv_island_count(self, uv_set: str = 'map1') -> int: ...
+
+    def color_set_display_values(
+        self,
+        name: str = 'colorSet1',
+        scale: Optional[float] = None,
+    ) -> numpy.ndarray: ...
+
+    def set_color_set_display_values(
+        self,
+        name: str,
+        values: numpy.ndarray,
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def fill_color_set(
+        self,
+        name: str,
+        color: list[float],
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def bake_ambient_occlusion(
+        self,
+        name: str = 'colorSet1',
+        channels: str = 'rgb',
+        sample_count: int = 64,
+        max_distance: Optional[float] = None,
+        scale: Optional[float] = None,
+    ) -> None: ...
+
+    def color_set_u8(self, name: str) -> numpy.ndarray: ...
+
+    def set_color_set_u8(self, name: str, values: numpy.ndarray) -> None: ...
+
+    def flip_normals(
+        self,
+        flip_vectors: bool = True,
+        reverse_winding: bool = True,
+    ) -> None: ...
+
+    def mirror(
+        self,
+        axis: str = 'x',
+        rename_bones: bool = True,
+        skel: Optional[SkelData] = None,
+    ) -> MeshObjectData: ...
+
+    def add_attribute(
+        self,
+        name: AttributeName,
+        data: numpy.ndarray,
+    ) -> None: ...
+
+    def validate_attributes(self) -> None: ...
+
+    def split_by_connectivity(self) -> list[MeshObjectData]: ...
+
+    def split_by_triangle_ids(
+        self,
+        triangle_ids: numpy.ndarray,
+    ) -> list[MeshObjectData]: ...
+
+    def split_by_vertex_count(
+        self,
+        max_vertex_count: int,
+    ) -> list[MeshObjectData]: ...
+
+    def bounding_sphere(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], float]: ...
+
+    def aabb(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[float]]: ...
+
+    def oriented_bounding_box(
+        self,
+        skel: Optional[SkelData] = None,
+    ) -> tuple[list[float], list[list[float]], list[float]]: ..."#
+                .to_string()
+        }
+    }
+
+    fn mesh_bounding_points(
+        py: Python,
+        mesh: &MeshData,
+        skel: Option<crate::skel_data::skel_data::SkelData>,
+    ) -> PyResult<Vec<glam::Vec3>> {
+        let mesh = mesh.clone().map_py(py)?;
+        let skel = skel.map(|s| s.map_py(py)).transpose()?;
+        let objects: Vec<_> = mesh.objects.iter().collect();
+        crate::mesh_bounding::bounding_points(&objects, skel.as_ref())
+            .map_err(MeshDataError::new_err)
+    }
+
+    fn object_bounding_points(
+        py: Python,
+        object: &MeshObjectData,
+        skel: Option<crate::skel_data::skel_data::SkelData>,
+    ) -> PyResult<Vec<glam::Vec3>> {
+        let object = object.clone().map_py(py)?;
+        let skel = skel.map(|s| s.map_py(py)).transpose()?;
+        crate::mesh_bounding::bounding_points(&[&object], skel.as_ref())
+            .map_err(MeshDataError::new_err)
+    }
+
+    // Use rows for the rotation to match the in game data.
+    fn obb_values(points: &[glam::Vec3]) -> ([f32; 3], [[f32; 3]; 3], [f32; 3]) {
+        let (center, rotation, size) = crate::mesh_bounding::oriented_bounding_box(points);
+        (
+            center.to_array(),
+            rotation.transpose().to_cols_array_2d(),
+            size.to_array(),
+        )
+    }
+
+    fn normal_weighting_rs(weighting: &str) -> PyResult<crate::mesh_normals::NormalWeighting> {
+        match weighting {
+            "uniform" => Ok(crate::mesh_normals::NormalWeighting::Uniform),
+            "area" => Ok(crate::mesh_normals::NormalWeighting::Area),
+            "angle" => Ok(crate::mesh_normals::NormalWeighting::Angle),
+            _ => Err(MeshDataError::new_err(format!(
+                "Unsupported normal weighting {weighting:?}. Expected 'uniform', 'area', or 'angle'."
+            ))),
+        }
+    }
+
+    fn color_channels_rs(channels: &str) -> PyResult<[bool; 4]> {
+        let mut result = [false; 4];
+        for c in channels.chars() {
+            match c {
+                'r' => result[0] = true,
+                'g' => result[1] = true,
+                'b' => result[2] = true,
+                'a' => result[3] = true,
+                _ => {
+                    return Err(MeshDataError::new_err(format!(
+                        "Unsupported color channels {channels:?}. Expected a combination of 'r', 'g', 'b', or 'a'."
+                    )))
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn color_values<T: numpy::Element + Copy>(
+        py: Python,
+        object_name: &str,
+        name: &str,
+        values: &Py<PyArray2<T>>,
+    ) -> PyResult<Vec<[T; 4]>> {
+        let (values, [_, columns]) = array2_values(py, values);
+        if columns != 4 {
+            return Err(MeshDataError::new_err(format!(
+                "Color set {name:?} for mesh object {object_name:?} has {columns} components but expected 4."
+            )));
+        }
+        Ok(values
+            .chunks_exact(4)
+            .map(|v| [v[0], v[1], v[2], v[3]])
+            .collect())
     }
 
     #[pyclass(get_all, set_all)]
@@ -139,18 +1221,124 @@ pub mod mesh_data {
     }
 
     #[pyclass(get_all, set_all)]
-    #[derive(Debug, Clone, MapPy, Pyi, PyRepr, PyInit)]
+    #[derive(Debug, Clone, Pyi, PyRepr)]
+    #[pyrepr("ssbh_data_py.mesh_data")]
+    #[pyi(has_methods = true)]
+    pub struct InfluenceArrays {
+        pub indices: Py<PyArray2<u32>>,
+
+        pub weights: Py<PyArray2<f32>>,
+
+        pub bone_names: TypedList<String>,
+    }
+
+    #[pymethods]
+    impl InfluenceArrays {
+        #[new]
+        fn new(
+            py: Python,
+            indices: Py<PyArray2<u32>>,
+            weights: Py<PyArray2<f32>>,
+            bone_names: Vec<String>,
+        ) -> PyResult<Self> {
+            let (indices, shape) = array2_values(py, &indices);
+            let (weights, weights_shape) = array2_values(py, &weights);
+            if shape != weights_shape {
+                return Err(MeshDataError::new_err(format!(
+                    "Expected the same shape for indices and weights but found {shape:?} and {weights_shape:?}."
+                )));
+            }
+            Self::from_values(py, indices, weights, shape, bone_names)
+        }
+
+        fn __repr__(&self) -> String {
+            self.py_repr()
+        }
+    }
+
+    impl InfluenceArrays {
+        fn from_values(
+            py: Python,
+            indices: Vec<u32>,
+            weights: Vec<f32>,
+            shape: [usize; 2],
+            bone_names: Vec<String>,
+        ) -> PyResult<Self> {
+            // Check the bone indices now instead of when saving.
+            crate::skin_weights::influences_from_arrays(&indices, &weights, shape[1], &bone_names)
+                .map_err(MeshDataError::new_err)?;
+            Ok(Self {
+                indices: indices.into_pyarray(py).reshape(shape)?.into(),
+                weights: weights.into_pyarray(py).reshape(shape)?.into(),
+                bone_names: bone_names.map_py(py)?,
+            })
+        }
+
+        fn from_influences(
+            py: Python,
+            influences: &[ssbh_data::mesh_data::BoneInfluence],
+            vertex_count: usize,
+        ) -> PyResult<Self> {
+            let arrays = crate::skin_weights::weight_arrays(influences, vertex_count, None)
+                .map_err(MeshDataError::new_err)?;
+            let shape = (vertex_count, arrays.influence_count);
+            Ok(Self {
+                indices: arrays.indices.into_pyarray(py).reshape(shape)?.into(),
+                weights: arrays.weights.into_pyarray(py).reshape(shape)?.into(),
+                bone_names: arrays.bone_names.map_py(py)?,
+            })
+        }
+
+        fn influences(&self, py: Python) -> PyResult<Vec<ssbh_data::mesh_data::BoneInfluence>> {
+            let (indices, shape) = array2_values(py, &self.indices);
+            let (weights, _) = array2_values(py, &self.weights);
+            let bone_names: Vec<String> = self.bone_names.list.extract(py)?;
+            crate::skin_weights::influences_from_arrays(&indices, &weights, shape[1], &bone_names)
+                .map_err(MeshDataError::new_err)
+        }
+    }
+
+    impl PyiMethods for InfluenceArrays {
+        fn pyi_methods() -> String {
+            r#"    def __init__(
+        self,
+        indices: numpy.ndarray,
+        weights: numpy.ndarray,
+        bone_names: list[str],
+    ) -> None: ..."#
+                .to_string()
+        }
+    }
+
+    #[pyclass(get_all, set_all)]
+    #[derive(Debug, Clone, MapPy, Pyi, PyRepr)]
     #[map(ssbh_data::mesh_data::AttributeData)]
     #[pyrepr("ssbh_data_py.mesh_data")]
     pub struct AttributeData {
         pub name: String,
 
-        #[pyinit(default = "numpy::PyArray2::zeros(py, [0, 0], false).into()")]
         #[pyi(default = "numpy.array([])")]
         #[map(from(map_from_vector_data), into(map_into_vector_data))]
         pub data: Py<PyArray2<f32>>,
     }
 
+    #[pymethods]
+    impl AttributeData {
+        #[new]
+        #[pyo3(signature = (name, data = None))]
+        fn new(py: Python, name: String, data: Option<Bound<'_, PyAny>>) -> PyResult<Self> {
+            let data = match data {
+                Some(data) => attribute_array(py, &data, &name, None)?,
+                None => numpy::PyArray2::zeros(py, [0, 0], false).into(),
+            };
+            Ok(Self { name, data })
+        }
+
+        fn __repr__(&self) -> String {
+            self.py_repr()
+        }
+    }
+
     #[pyfunction]
     fn read_mesh(py: Python, path: &str) -> PyResult<MeshData> {
         ssbh_data::mesh_data::MeshData::from_file(path)
@@ -158,6 +1346,75 @@ pub mod mesh_data {
             .map_py(py)
     }
 
+    #[pyfunction]
+    fn export_obj(py: Python, mesh: MeshData, path: &str) -> PyResult<()> {
+        let obj = crate::obj::write_obj(&mesh.map_py(py)?).map_err(MeshDataError::new_err)?;
+        std::fs::write(path, obj).map_err(|e| MeshDataError::new_err(format!("{e}")))
+    }
+
+    #[pyfunction]
+    fn import_obj(py: Python, path: &str) -> PyResult<MeshData> {
+        let obj =
+            std::fs::read_to_string(path).map_err(|e| MeshDataError::new_err(format!("{e}")))?;
+        crate::obj::read_obj(&obj)
+            .map_err(MeshDataError::new_err)?
+            .map_py(py)
+    }
+
+    #[pyfunction]
+    fn export_smd(
+        py: Python,
+        mesh: MeshData,
+        skel: crate::skel_data::skel_data::SkelData,
+        path: &str,
+    ) -> PyResult<()> {
+        let smd = crate::smd::write_smd_mesh(&mesh.map_py(py)?, &skel.map_py(py)?)
+            .map_err(MeshDataError::new_err)?;
+        std::fs::write(path, smd).map_err(|e| MeshDataError::new_err(format!("{e}")))
+    }
+
+    #[pyfunction]
+    fn import_smd(
+        py: Python,
+        path: &str,
+    ) -> PyResult<(MeshData, crate::skel_data::skel_data::SkelData)> {
+        let smd =
+            std::fs::read_to_string(path).map_err(|e| MeshDataError::new_err(format!("{e}")))?;
+        let (mesh, skel) = crate::smd::read_smd_mesh(&smd).map_err(MeshDataError::new_err)?;
+        Ok((mesh.map_py(py)?, skel.map_py(py)?))
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (objects, skel = None))]
+    fn merge_mesh_objects(
+        py: Python,
+        objects: Vec<MeshObjectData>,
+        skel: Option<crate::skel_data::skel_data::SkelData>,
+    ) -> PyResult<MeshObjectData> {
+        let objects = objects
+            .into_iter()
+            .map(|o| o.map_py(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        let skel = skel.map(|s| s.map_py(py)).transpose()?;
+        crate::mesh_split::merge_objects(&objects, skel.as_ref())
+            .map_err(MeshDataError::new_err)?
+            .map_py(py)
+    }
+
+    #[pyfunction]
+    fn color_set_scale(name: &str) -> Option<f32> {
+        crate::mesh_colors::color_set_scale(name)
+    }
+
+    #[pyfunction]
+    fn attribute_names(py: Python) -> PyResult<Py<pyo3::types::PyDict>> {
+        let names = pyo3::types::PyDict::new(py);
+        for schema in &crate::mesh_attributes::ATTRIBUTE_SCHEMAS {
+            names.set_item(schema.list, schema.names.to_vec())?;
+        }
+        Ok(names.unbind())
+    }
+
     #[pyfunction]
     fn transform_points(
         py: Python,
@@ -238,6 +1495,57 @@ pub fn map_into_vector_data(value: Py<PyArray2<f32>>, py: Python) -> PyResult<Ve
     }
 }
 
+/// The `AttributeName` type alias for the known attribute names in the generated stubs.
+pub fn attribute_name_pyi() -> String {
+    let names: Vec<_> = crate::mesh_attributes::ATTRIBUTE_SCHEMAS
+        .iter()
+        .flat_map(|s| s.names)
+        .map(|n| format!("'{n}'"))
+        .collect();
+    format!("AttributeName = Literal[{}]", names.join(", "))
+}
+
+// Convert any 2D array to floats and check the component count before saving.
+fn attribute_array(
+    py: Python,
+    data: &Bound<'_, PyAny>,
+    name: &str,
+    object_name: Option<&str>,
+) -> PyResult<Py<PyArray2<f32>>> {
+    let data = data
+        .extract::<numpy::PyArrayLike2<f32, numpy::AllowTypeChange>>()
+        .map_err(|e| {
+            MeshDataError::new_err(format!(
+                "{} does not have a 2D array of attribute values: {e}",
+                crate::mesh_attributes::attribute_description(name, object_name)
+            ))
+        })?;
+    let [rows, columns] = [data.shape()[0], data.shape()[1]];
+    if rows > 0 {
+        crate::mesh_attributes::check_array_components(object_name, name, columns)
+            .map_err(MeshDataError::new_err)?;
+    }
+    Ok(data.as_unbound().clone_ref(py))
+}
+
+fn array2_shape<T: numpy::Element>(py: Python, array: &Py<PyArray2<T>>) -> [usize; 2] {
+    let shape = array.bind(py).shape();
+    [shape[0], shape[1]]
+}
+
+// Get the values in row major order and the shape of the array.
+fn array2_values<T: numpy::Element + Copy>(
+    py: Python,
+    array: &Py<PyArray2<T>>,
+) -> (Vec<T>, [usize; 2]) {
+    let array = array.bind(py).readonly();
+    let array = array.as_array();
+    (
+        array.iter().copied().collect(),
+        [array.nrows(), array.ncols()],
+    )
+}
+
 fn vectors_pyarray<const N: usize>(py: Python, values: &[[f32; N]]) -> PyResult<Py<PyArray2<f32>>> {
     // This flatten will be optimized in Release mode.
     // This avoids needing unsafe code.
@@ -0,0 +1,127 @@
+use ssbh_data::mesh_data::{MeshObjectData, VectorData};
+use ssbh_data::skel_data::SkelData;
+
+use crate::mesh_transform::{bone_world_transform, parent_bone_to_model_space, transform_object};
+
+/// Reflects `object` across the plane through the origin perpendicular to `axis`.
+///
+/// Positions, normals, binormals, and tangents are reflected, and the tangent W sign is flipped
+/// since reflections change the handedness of the tangent space.
+/// The vertex order of each triangle is reversed to preserve the facing direction.
+/// If `rename_bones` is set, left and right bone names are swapped using [mirrored_bone_name].
+/// If `skel` is set, bones are only renamed if the mirrored name is in the skeleton,
+/// and objects with a parent bone are reflected in model space instead of the parent bone's space.
+pub fn mirror_object(
+    object: &mut MeshObjectData,
+    axis: &str,
+    rename_bones: bool,
+    skel: Option<&SkelData>,
+) -> Result<(), String> {
+    let axis = axis_index(axis)?;
+
+    if let Some(skel) = skel {
+        parent_bone_to_model_space(object, skel)?;
+    }
+
+    for attribute in object
+        .positions
+        .iter_mut()
+        .chain(object.normals.iter_mut())
+        .chain(object.binormals.iter_mut())
+    {
+        reflect(&mut attribute.data, axis, false);
+    }
+    for attribute in &mut object.tangents {
+        reflect(&mut attribute.data, axis, true);
+    }
+
+    for face in object.vertex_indices.chunks_exact_mut(3) {
+        face.swap(1, 2);
+    }
+
+    if rename_bones {
+        let rename = |name: &str| {
+            let mirrored = mirrored_bone_name(name);
+            match skel {
+                Some(skel) if !skel.bones.iter().any(|b| b.name == mirrored) => name.to_string(),
+                _ => mirrored,
+            }
+        };
+        object.parent_bone_name = rename(&object.parent_bone_name);
+        for influence in &mut object.bone_influences {
+            influence.bone_name = rename(&influence.bone_name);
+        }
+    }
+
+    if let Some(skel) = skel {
+        if object.bone_influences.is_empty() && !object.parent_bone_name.is_empty() {
+            let transform = bone_world_transform(skel, &object.parent_bone_name)?;
+            transform_object(object, transform.inverse());
+        }
+    }
+
+    Ok(())
+}
+
+/// Swaps left and right in `name` or returns `name` unchanged if there is no side.
+///
+/// This handles "Left" and "Right" as well as single "L" and "R" characters
+/// like "ArmL", "FingerR1", "L_Hand", or "Arm.R".
+/// A single character is only swapped if it isn't part of a longer word like "Root".
+pub fn mirrored_bone_name(name: &str) -> String {
+    for (a, b) in [("Left", "Right"), ("left", "right"), ("LEFT", "RIGHT")] {
+        if name.contains(a) {
+            return name.replace(a, b);
+        }
+        if name.contains(b) {
+            return name.replace(b, a);
+        }
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    let is_separator = |c: &char| matches!(c, '_' | '.' | '-' | ' ');
+    let is_side = |i: usize| {
+        let before = i.checked_sub(1).map(|j| chars[j]);
+        let after = chars.get(i + 1);
+        before.is_none_or(|c| is_separator(&c) || c.is_lowercase() || c.is_ascii_digit())
+            && after.is_none_or(|c| is_separator(c) || c.is_ascii_digit())
+    };
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match c {
+            'L' if is_side(i) => 'R',
+            'R' if is_side(i) => 'L',
+            _ => *c,
+        })
+        .collect()
+}
+
+fn axis_index(axis: &str) -> Result<usize, String> {
+    match axis {
+        "x" | "X" => Ok(0),
+        "y" | "Y" => Ok(1),
+        "z" | "Z" => Ok(2),
+        _ => Err(format!(
+            "Unsupported axis {axis:?}. Expected 'x', 'y', or 'z'."
+        )),
+    }
+}
+
+fn reflect(data: &mut VectorData, axis: usize, flip_w: bool) {
+    match data {
+        VectorData::Vector2(v) => v.iter_mut().for_each(|v| {
+            if let Some(x) = v.get_mut(axis) {
+                *x = -*x;
+            }
+        }),
+        VectorData::Vector3(v) => v.iter_mut().for_each(|v| v[axis] = -v[axis]),
+        VectorData::Vector4(v) => v.iter_mut().for_each(|v| {
+            v[axis] = -v[axis];
+            if flip_w {
+                v[3] = -v[3];
+            }
+        }),
+    }
+}
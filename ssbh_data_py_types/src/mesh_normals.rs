@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+use ssbh_data::mesh_data::{AttributeData, MeshObjectData, VectorData};
+
+use crate::mesh_cleanup::{append_vertex_copies, checked_vertex_count};
+use crate::mesh_split::{find, union};
+use crate::vector_data::vectors3;
+
+/// How face normals contribute to the normal of each vertex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalWeighting {
+    /// Each face contributes equally.
+    Uniform,
+    /// Faces contribute based on their area.
+    Area,
+    /// Faces contribute based on the angle of the face at the vertex.
+    Angle,
+}
+
+/// Recalculates the first normal attribute of `object` and returns the number of added vertices.
+///
+/// Normals are averaged for faces sharing a vertex position,
+/// including vertices duplicated along UV seams.
+/// Adjacent faces with normals more than `angle_threshold` degrees apart are not averaged.
+/// Faces are still averaged if they are connected by a path of faces sharing edges within the threshold.
+/// Degenerate faces don't contribute to the normals.
+/// If `preserve_split_normals` is set, faces with different existing normals are also not averaged.
+/// Vertices shared by faces with different normals are duplicated along with all their attributes and weights.
+pub fn recalculate_normals(
+    object: &mut MeshObjectData,
+    angle_threshold: f32,
+    weighting: NormalWeighting,
+    preserve_split_normals: bool,
+) -> Result<usize, String> {
+    let count = checked_vertex_count(object)?;
+    if !object.vertex_indices.len().is_multiple_of(3) {
+        return Err(format!(
+            "Vertex index count {} for mesh object {:?} is not a multiple of 3.",
+            object.vertex_indices.len(),
+            object.name
+        ));
+    }
+    let positions: Vec<Vec3> = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data).into_iter().map(Vec3::from).collect())
+        .ok_or_else(|| format!("Mesh object {:?} has no positions.", object.name))?;
+    let existing_normals: Option<Vec<Vec3>> = object
+        .normals
+        .first()
+        .map(|n| vectors3(&n.data).into_iter().map(Vec3::from).collect());
+
+    let corners = &object.vertex_indices;
+    let face_normals: Vec<Vec3> = corners
+        .chunks_exact(3)
+        .map(|f| {
+            let [a, b, c] = [f[0], f[1], f[2]].map(|i| positions[i as usize]);
+            (b - a).cross(c - a)
+        })
+        .collect();
+
+    // Degenerate faces have no direction and may have zero length edges.
+    let is_degenerate = |corner: usize| face_normals[corner / 3].normalize_or_zero() == Vec3::ZERO;
+
+    let corner_weights: Vec<Vec3> = corners
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            let face = i / 3;
+            let normal = face_normals[face];
+            if is_degenerate(i) {
+                return Vec3::ZERO;
+            }
+            match weighting {
+                NormalWeighting::Uniform => normal.normalize(),
+                NormalWeighting::Area => normal,
+                NormalWeighting::Angle => {
+                    let f = &corners[face * 3..face * 3 + 3];
+                    let p = positions[corners[i] as usize];
+                    let next = positions[f[(i + 1) % 3] as usize] - p;
+                    let previous = positions[f[(i + 2) % 3] as usize] - p;
+                    if next == Vec3::ZERO || previous == Vec3::ZERO {
+                        Vec3::ZERO
+                    } else {
+                        normal.normalize() * next.angle_between(previous)
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let corner_keys: Vec<[u32; 3]> = corners
+        .iter()
+        .map(|v| positions[*v as usize].to_array().map(f32::to_bits))
+        .collect();
+    let mut position_corners: HashMap<[u32; 3], Vec<usize>> = HashMap::new();
+    for (i, key) in corner_keys.iter().enumerate() {
+        position_corners.entry(*key).or_default().push(i);
+    }
+
+    let min_dot = angle_threshold.to_radians().cos();
+    let is_smooth = |a: usize, b: usize| {
+        let (na, nb) = (
+            face_normals[a / 3].normalize(),
+            face_normals[b / 3].normalize(),
+        );
+        let is_preserved = match (&existing_normals, preserve_split_normals) {
+            (Some(normals), true) => {
+                let ea = normals[corners[a] as usize].normalize_or_zero();
+                let eb = normals[corners[b] as usize].normalize_or_zero();
+                ea.dot(eb) < 0.9999
+            }
+            _ => false,
+        };
+        !is_preserved && na.dot(nb) >= min_dot - 1e-6
+    };
+
+    // Join faces around each position that share an edge and are smooth.
+    // Faces connected through other smooth faces are also joined,
+    // so gradually curved surfaces stay smooth even if the faces at the ends are not.
+    let mut parents: Vec<usize> = (0..corners.len()).collect();
+    for group_corners in position_corners.values() {
+        let mut edge_corners: HashMap<[u32; 3], Vec<usize>> = HashMap::new();
+        for c in group_corners.iter().filter(|c| !is_degenerate(**c)) {
+            let face = c / 3 * 3;
+            for other in (face..face + 3).filter(|o| o != c) {
+                edge_corners.entry(corner_keys[other]).or_default().push(*c);
+            }
+        }
+        for edge in edge_corners.values() {
+            for (i, a) in edge.iter().enumerate() {
+                for b in &edge[i + 1..] {
+                    if is_smooth(*a, *b) {
+                        union(&mut parents, *a, *b);
+                    }
+                }
+            }
+        }
+    }
+
+    // Degenerate corners use the group of another corner for the same vertex or position if possible.
+    let mut vertex_roots: HashMap<u32, usize> = HashMap::new();
+    let mut position_roots: HashMap<[u32; 3], usize> = HashMap::new();
+    for c in (0..corners.len()).filter(|c| !is_degenerate(*c)) {
+        let root = find(&mut parents, c);
+        vertex_roots.entry(corners[c]).or_insert(root);
+        position_roots.entry(corner_keys[c]).or_insert(root);
+    }
+    for c in (0..corners.len()).filter(|c| is_degenerate(*c)) {
+        if let Some(root) = vertex_roots
+            .get(&corners[c])
+            .or_else(|| position_roots.get(&corner_keys[c]))
+        {
+            parents[c] = *root;
+        }
+    }
+
+    let mut corner_groups = vec![0; corners.len()];
+    let mut root_groups: HashMap<usize, usize> = HashMap::new();
+    let mut group_normals: Vec<Vec3> = Vec::new();
+    for (c, weight) in corner_weights.iter().enumerate() {
+        let root = find(&mut parents, c);
+        let group = *root_groups.entry(root).or_insert_with(|| {
+            group_normals.push(Vec3::ZERO);
+            group_normals.len() - 1
+        });
+        group_normals[group] += *weight;
+        corner_groups[c] = group;
+    }
+    for normal in &mut group_normals {
+        *normal = normal.normalize_or_zero();
+    }
+
+    // Duplicate vertices used by more than one group.
+    let mut vertex_groups: Vec<Option<usize>> = vec![None; count];
+    let mut split_vertices: HashMap<(u32, usize), u32> = HashMap::new();
+    let mut sources = Vec::new();
+    let mut vertex_indices = corners.clone();
+    for (i, v) in vertex_indices.iter_mut().enumerate() {
+        let group = corner_groups[i];
+        match vertex_groups[*v as usize] {
+            None => vertex_groups[*v as usize] = Some(group),
+            Some(g) if g == group => (),
+            Some(_) => {
+                *v = *split_vertices.entry((*v, group)).or_insert_with(|| {
+                    sources.push(*v as usize);
+                    (count + sources.len() - 1) as u32
+                });
+            }
+        }
+    }
+
+    let mut normals = vec![Vec3::ZERO; count + sources.len()];
+    for (i, v) in vertex_indices.iter().enumerate() {
+        normals[*v as usize] = group_normals[corner_groups[i]];
+    }
+
+    append_vertex_copies(object, &sources);
+    object.vertex_indices = vertex_indices;
+    set_normals(object, &normals);
+
+    Ok(sources.len())
+}
+
+/// Negates normals, binormals, and tangents and optionally reverses the vertex order of each triangle.
+/// The tangent W component is not changed.
+pub fn flip_normals(object: &mut MeshObjectData, flip_vectors: bool, reverse_winding: bool) {
+    if flip_vectors {
+        for attribute in object
+            .normals
+            .iter_mut()
+            .chain(object.binormals.iter_mut())
+            .chain(object.tangents.iter_mut())
+        {
+            match &mut attribute.data {
+                VectorData::Vector2(v) => v.iter_mut().flatten().for_each(|x| *x = -*x),
+                VectorData::Vector3(v) => v.iter_mut().flatten().for_each(|x| *x = -*x),
+                VectorData::Vector4(v) => v.iter_mut().for_each(|v| {
+                    v[0] = -v[0];
+                    v[1] = -v[1];
+                    v[2] = -v[2];
+                }),
+            }
+        }
+    }
+
+    if reverse_winding {
+        for face in object.vertex_indices.chunks_exact_mut(3) {
+            face.swap(1, 2);
+        }
+    }
+}
+
+// Keep the existing format for normals or add 4 component normals like in-game meshes.
+fn set_normals(object: &mut MeshObjectData, normals: &[Vec3]) {
+    let data = match object.normals.first().map(|n| &n.data) {
+        Some(VectorData::Vector3(_)) => {
+            VectorData::Vector3(normals.iter().map(|n| n.to_array()).collect())
+        }
+        _ => VectorData::Vector4(normals.iter().map(|n| n.extend(0.0).to_array()).collect()),
+    };
+    match object.normals.first_mut() {
+        Some(attribute) => attribute.data = data,
+        None => object.normals.push(AttributeData {
+            name: "Normal0".to_string(),
+            data,
+        }),
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::Vec3;
+use meshopt::DecodePosition;
+use ssbh_data::mesh_data::{BoneInfluence, MeshObjectData, VectorData, VertexWeight};
+
+use crate::mesh_cleanup::{checked_vertex_count, rebuild_vertices, remove_unused_vertices};
+use crate::vector_data::vectors3;
+
+struct Position([f32; 3]);
+
+impl DecodePosition for Position {
+    fn decode_position(&self) -> [f32; 3] {
+        self.0
+    }
+}
+
+/// Reorders the triangles in `object` to reduce the number of vertex shader invocations.
+pub fn optimize_vertex_cache(object: &mut MeshObjectData) -> Result<(), String> {
+    let count = checked_triangles(object)?;
+    object.vertex_indices = meshopt::optimize_vertex_cache(&object.vertex_indices, count);
+    Ok(())
+}
+
+/// Reorders the triangles in `object` to reduce the vertex shader invocations and pixel overdraw.
+///
+/// The `threshold` is how much the vertex cache efficiency can degrade
+/// to reduce overdraw like 1.05 for up to 5% worse.
+pub fn optimize_overdraw(object: &mut MeshObjectData, threshold: f32) -> Result<(), String> {
+    let positions: Vec<_> = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .ok_or_else(|| format!("Mesh object {:?} has no positions.", object.name))?
+        .into_iter()
+        .map(Position)
+        .collect();
+
+    // The overdraw optimization expects indices already optimized for the vertex cache.
+    optimize_vertex_cache(object)?;
+    meshopt::optimize_overdraw_in_place_decoder(&mut object.vertex_indices, &positions, threshold);
+    Ok(())
+}
+
+/// Reorders the vertices in `object` in the order they are first used by the vertex indices
+/// to reduce the number of memory fetches during vertex processing.
+///
+/// All attributes, bone influences, and vertex indices are updated to the new order.
+/// Unused vertices are moved to the end.
+pub fn optimize_vertex_fetch(object: &mut MeshObjectData) -> Result<(), String> {
+    let count = checked_vertex_count(object)?;
+
+    let mut remap = vec![u32::MAX; count];
+    let mut kept = Vec::with_capacity(count);
+    let used = object.vertex_indices.iter().map(|i| *i as usize);
+    for i in used.chain(0..count) {
+        if remap[i] == u32::MAX {
+            remap[i] = kept.len() as u32;
+            kept.push(i);
+        }
+    }
+
+    rebuild_vertices(object, &kept, &remap);
+    Ok(())
+}
+
+/// Reduces the triangles in `object` using quadric error edge collapses
+/// and returns the number of removed triangles.
+///
+/// Simplification stops at `target_triangle_count` or when the error would exceed `target_error`
+/// relative to the size of the object.
+/// The error only measures changes to positions, so normals and other attributes don't affect
+/// which edges are collapsed.
+/// Vertices with the same position but different attributes like UV seams or hard edges
+/// are only collapsed along the seam.
+///
+/// Vertices are collapsed onto existing vertices that keep their positions.
+/// The bone weights, normals, and texture coordinates of each remaining vertex are averaged
+/// with the vertices collapsed onto it.
+/// Blended weights are normalized to sum to 1.0, and blended normals are normalized.
+/// Other attributes keep the values of the remaining vertices.
+/// Unused vertices are removed afterwards.
+pub fn simplify(
+    object: &mut MeshObjectData,
+    target_triangle_count: usize,
+    target_error: f32,
+) -> Result<usize, String> {
+    checked_triangles(object)?;
+    let positions = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .ok_or_else(|| format!("Mesh object {:?} has no positions.", object.name))?;
+    let vertices: Vec<_> = positions.iter().copied().map(Position).collect();
+
+    let triangle_count = object.vertex_indices.len() / 3;
+    let indices = meshopt::simplify_decoder(
+        &object.vertex_indices,
+        &vertices,
+        target_triangle_count * 3,
+        target_error,
+    );
+
+    let groups = collapsed_vertices(&object.vertex_indices, &indices, &positions);
+    blend_collapsed_vertices(object, &groups);
+
+    object.vertex_indices = indices;
+    remove_unused_vertices(object)?;
+    Ok(triangle_count - object.vertex_indices.len() / 3)
+}
+
+// meshopt doesn't return the collapsed edges, so assign each removed vertex to the closest
+// remaining vertex connected to it through removed vertices in the original triangles.
+// Vertices on seams and borders are only collapsed along the seam or border,
+// so prefer remaining vertices on open edges for removed vertices on open edges.
+// Returns the vertices for each remaining vertex including itself.
+fn collapsed_vertices(
+    original_indices: &[u32],
+    indices: &[u32],
+    positions: &[[f32; 3]],
+) -> Vec<Vec<usize>> {
+    let mut is_kept = vec![false; positions.len()];
+    for i in indices {
+        is_kept[*i as usize] = true;
+    }
+
+    let mut neighbors = vec![Vec::new(); positions.len()];
+    let mut edge_counts: HashMap<(usize, usize), usize> = HashMap::new();
+    for t in original_indices.chunks_exact(3) {
+        for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            let (a, b) = (a as usize, b as usize);
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+            *edge_counts.entry((a.min(b), a.max(b))).or_default() += 1;
+        }
+    }
+
+    // UV seams and hard edges split vertices, so seams are open edges like borders.
+    let mut is_open = vec![false; positions.len()];
+    for ((a, b), _) in edge_counts.into_iter().filter(|(_, count)| *count == 1) {
+        is_open[a] = true;
+        is_open[b] = true;
+    }
+
+    let mut groups = vec![Vec::new(); positions.len()];
+    for v in 0..positions.len() {
+        if is_kept[v] {
+            groups[v].push(v);
+            continue;
+        }
+
+        let along_open_edges = |n: usize| is_open[n];
+        let target = if is_open[v] {
+            closest_kept_vertex(v, positions, &neighbors, &is_kept, along_open_edges)
+                .or_else(|| closest_kept_vertex(v, positions, &neighbors, &is_kept, |_| true))
+        } else {
+            closest_kept_vertex(v, positions, &neighbors, &is_kept, |_| true)
+        };
+        if let Some(n) = target {
+            groups[n].push(v);
+        }
+    }
+    groups
+}
+
+// Search outward through the vertices allowed by `filter`
+// until finding the closest remaining vertex at the smallest edge distance.
+fn closest_kept_vertex(
+    v: usize,
+    positions: &[[f32; 3]],
+    neighbors: &[Vec<usize>],
+    is_kept: &[bool],
+    filter: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    let position = Vec3::from(positions[v]);
+    let mut visited = HashSet::from([v]);
+    let mut frontier = vec![v];
+    while !frontier.is_empty() {
+        let mut next = Vec::new();
+        let mut closest: Option<(usize, f32)> = None;
+        for u in frontier {
+            for &n in &neighbors[u] {
+                if !filter(n) || !visited.insert(n) {
+                    continue;
+                }
+                if is_kept[n] {
+                    let distance = position.distance_squared(Vec3::from(positions[n]));
+                    if closest.is_none_or(|(_, d)| distance < d) {
+                        closest = Some((n, distance));
+                    }
+                } else {
+                    next.push(n);
+                }
+            }
+        }
+        if let Some((n, _)) = closest {
+            return Some(n);
+        }
+        frontier = next;
+    }
+    None
+}
+
+fn blend_collapsed_vertices(object: &mut MeshObjectData, groups: &[Vec<usize>]) {
+    for attribute in &mut object.normals {
+        blend_vector_data(&mut attribute.data, groups, true);
+    }
+    for attribute in &mut object.texture_coordinates {
+        blend_vector_data(&mut attribute.data, groups, false);
+    }
+    blend_weights(&mut object.bone_influences, groups);
+}
+
+fn blend_vector_data(data: &mut VectorData, groups: &[Vec<usize>], normalize: bool) {
+    match data {
+        VectorData::Vector2(v) => blend_vectors(v, groups, normalize),
+        VectorData::Vector3(v) => blend_vectors(v, groups, normalize),
+        VectorData::Vector4(v) => blend_vectors(v, groups, normalize),
+    }
+}
+
+fn blend_vectors<const N: usize>(values: &mut [[f32; N]], groups: &[Vec<usize>], normalize: bool) {
+    let original = values.to_vec();
+    for (i, group) in groups.iter().enumerate() {
+        if group.len() <= 1 {
+            continue;
+        }
+
+        let mut value = [0.0; N];
+        for v in group {
+            for (c, component) in value.iter_mut().enumerate() {
+                *component += original[*v][c] / group.len() as f32;
+            }
+        }
+        if normalize {
+            // Only normalize XYZ for 4 component normals.
+            let xyz = &mut value[..N.min(3)];
+            let length = xyz.iter().map(|c| c * c).sum::<f32>().sqrt();
+            if length > 0.0 {
+                xyz.iter_mut().for_each(|c| *c /= length);
+            }
+        }
+        values[i] = value;
+    }
+}
+
+fn blend_weights(influences: &mut [BoneInfluence], groups: &[Vec<usize>]) {
+    // Find the influence index and weight for each vertex.
+    let mut vertex_weights: Vec<Vec<(usize, f32)>> = vec![Vec::new(); groups.len()];
+    for (i, influence) in influences.iter().enumerate() {
+        for w in &influence.vertex_weights {
+            if let Some(weights) = vertex_weights.get_mut(w.vertex_index as usize) {
+                weights.push((i, w.vertex_weight));
+            }
+        }
+    }
+
+    let blended: Vec<Option<Vec<(usize, f32)>>> = groups
+        .iter()
+        .map(|group| {
+            if group.len() <= 1 {
+                return None;
+            }
+            let mut weights: Vec<(usize, f32)> = Vec::new();
+            for (influence, weight) in group.iter().flat_map(|v| &vertex_weights[*v]) {
+                match weights.iter_mut().find(|(i, _)| i == influence) {
+                    Some((_, w)) => *w += weight,
+                    None => weights.push((*influence, *weight)),
+                }
+            }
+            let total: f32 = weights.iter().map(|(_, w)| w).sum();
+            if total > 0.0 {
+                weights.iter_mut().for_each(|(_, w)| *w /= total);
+            }
+            Some(weights)
+        })
+        .collect();
+
+    let mut blended_weights = vec![Vec::new(); influences.len()];
+    for (v, weights) in blended.iter().enumerate() {
+        for (i, weight) in weights.iter().flatten() {
+            if *weight > 0.0 {
+                blended_weights[*i].push(VertexWeight {
+                    vertex_index: v as u32,
+                    vertex_weight: *weight,
+                });
+            }
+        }
+    }
+
+    for (influence, weights) in influences.iter_mut().zip(blended_weights) {
+        influence.vertex_weights.retain(|w| {
+            blended
+                .get(w.vertex_index as usize)
+                .is_none_or(|b| b.is_none())
+        });
+        influence.vertex_weights.extend(weights);
+        influence.vertex_weights.sort_by_key(|w| w.vertex_index);
+    }
+}
+
+fn checked_triangles(object: &MeshObjectData) -> Result<usize, String> {
+    let count = checked_vertex_count(object)?;
+    if !object.vertex_indices.len().is_multiple_of(3) {
+        return Err(format!(
+            "Vertex index count {} for mesh object {:?} is not a multiple of 3.",
+            object.vertex_indices.len(),
+            object.name
+        ));
+    }
+    Ok(count)
+}
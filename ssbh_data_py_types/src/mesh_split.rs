@@ -0,0 +1,378 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use ssbh_data::mesh_data::{
+    AttributeData, BoneInfluence, MeshData, MeshObjectData, VectorData, VertexWeight,
+};
+use ssbh_data::modl_data::{ModlData, ModlEntryData};
+use ssbh_data::skel_data::SkelData;
+
+use crate::mesh_cleanup::{checked_vertex_count, remove_unused_vertices};
+use crate::mesh_transform::parent_bone_to_model_space;
+use crate::vector_data::{vector_components, vectors2, vectors3, vectors4};
+
+/// Creates an object for each unique value in `triangle_ids` in ascending order.
+/// Each object only contains the vertices used by its triangles.
+///
+/// Each object keeps the name and subindex of `object`.
+/// Use [update_subindices] after adding the objects to a mesh to make the subindices unique.
+pub fn split_by_triangle_ids(
+    object: &MeshObjectData,
+    triangle_ids: &[u32],
+) -> Result<Vec<MeshObjectData>, String> {
+    checked_vertex_count(object)?;
+    let triangle_count = object.vertex_indices.len() / 3;
+    if triangle_ids.len() != triangle_count {
+        return Err(format!(
+            "Expected {triangle_count} triangle ids for mesh object {:?} but found {}.",
+            object.name,
+            triangle_ids.len()
+        ));
+    }
+
+    let mut triangles: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for (id, face) in triangle_ids
+        .iter()
+        .zip(object.vertex_indices.chunks_exact(3))
+    {
+        triangles.entry(*id).or_default().extend_from_slice(face);
+    }
+
+    triangles
+        .into_values()
+        .map(|vertex_indices| {
+            let mut part = object.clone();
+            part.vertex_indices = vertex_indices;
+            remove_unused_vertices(&mut part)?;
+            Ok(part)
+        })
+        .collect()
+}
+
+/// Finds the connected component for each triangle.
+/// Triangles are connected if they share a vertex or a vertex with the same position.
+/// Components are numbered in the order they first appear.
+pub fn connected_components(object: &MeshObjectData) -> Result<Vec<u32>, String> {
+    let count = checked_vertex_count(object)?;
+
+    let mut parents: Vec<usize> = (0..count).collect();
+
+    // Vertices are often duplicated along UV seams or hard edges.
+    if let Some(positions) = object.positions.first() {
+        let mut vertices: HashMap<[u32; 3], usize> = HashMap::new();
+        for (i, p) in vectors3(&positions.data).iter().enumerate() {
+            let key = p.map(|f| f.to_bits());
+            match vertices.get(&key) {
+                Some(j) => union(&mut parents, *j, i),
+                None => {
+                    vertices.insert(key, i);
+                }
+            }
+        }
+    }
+    for face in object.vertex_indices.chunks_exact(3) {
+        union(&mut parents, face[0] as usize, face[1] as usize);
+        union(&mut parents, face[0] as usize, face[2] as usize);
+    }
+
+    let mut ids = HashMap::new();
+    Ok(object
+        .vertex_indices
+        .chunks_exact(3)
+        .map(|face| {
+            let root = find(&mut parents, face[0] as usize);
+            let next_id = ids.len() as u32;
+            *ids.entry(root).or_insert(next_id)
+        })
+        .collect())
+}
+
+// Find the root of a set in a disjoint set forest.
+pub fn find(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    // Compress the path to speed up later searches.
+    let mut i = i;
+    while parents[i] != root {
+        let next = parents[i];
+        parents[i] = root;
+        i = next;
+    }
+    root
+}
+
+pub fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    if a != b {
+        parents[b] = a;
+    }
+}
+
+/// Groups triangles in order so that each group uses at most `max_vertex_count` vertices.
+pub fn vertex_count_groups(
+    object: &MeshObjectData,
+    max_vertex_count: usize,
+) -> Result<Vec<u32>, String> {
+    checked_vertex_count(object)?;
+    if max_vertex_count < 3 {
+        return Err(format!(
+            "Expected a vertex count limit of at least 3 but found {max_vertex_count}."
+        ));
+    }
+
+    let mut group = 0;
+    let mut vertices = HashSet::new();
+    Ok(object
+        .vertex_indices
+        .chunks_exact(3)
+        .map(|face| {
+            let new_vertices = face.iter().filter(|i| !vertices.contains(*i)).count();
+            if vertices.len() + new_vertices > max_vertex_count {
+                group += 1;
+                vertices.clear();
+            }
+            vertices.extend(face.iter().copied());
+            group
+        })
+        .collect())
+}
+
+/// Combines `objects` into a single object using the name and settings of the first object.
+///
+/// Attributes are combined by name, and objects without an attribute use zeros for those vertices.
+/// Bone influences are combined by bone name.
+/// If any object uses vertex skinning or the parent bones differ,
+/// objects with a parent bone are fully weighted to their parent bone.
+/// This requires `skel` to transform their vertices from the parent bone's space to model space.
+pub fn merge_objects(
+    objects: &[MeshObjectData],
+    skel: Option<&SkelData>,
+) -> Result<MeshObjectData, String> {
+    let first = objects
+        .first()
+        .ok_or_else(|| "Expected at least one mesh object to merge.".to_string())?;
+    let counts = objects
+        .iter()
+        .map(checked_vertex_count)
+        .collect::<Result<Vec<_>, _>>()?;
+    let offsets: Vec<u32> = counts
+        .iter()
+        .scan(0, |offset, count| {
+            let start = *offset;
+            *offset += *count as u32;
+            Some(start)
+        })
+        .collect();
+
+    let use_weights = objects.iter().any(|o| !o.bone_influences.is_empty())
+        || objects
+            .iter()
+            .any(|o| o.parent_bone_name != first.parent_bone_name);
+
+    let transformed;
+    let objects = if use_weights {
+        transformed = objects
+            .iter()
+            .map(|o| {
+                let mut o = o.clone();
+                if o.bone_influences.is_empty() && !o.parent_bone_name.is_empty() {
+                    let skel = skel.ok_or_else(|| {
+                        format!(
+                            "A skeleton is required to weight mesh object {:?} to its parent bone {:?}.",
+                            o.name, o.parent_bone_name
+                        )
+                    })?;
+                    parent_bone_to_model_space(&mut o, skel)?;
+                }
+                Ok(o)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        &transformed[..]
+    } else {
+        objects
+    };
+
+    let mut bone_influences: Vec<BoneInfluence> = Vec::new();
+    if use_weights {
+        for ((object, offset), count) in objects.iter().zip(&offsets).zip(&counts) {
+            let parent_weights;
+            let influences = if object.bone_influences.is_empty() {
+                if object.parent_bone_name.is_empty() {
+                    continue;
+                }
+                parent_weights = [BoneInfluence {
+                    bone_name: object.parent_bone_name.clone(),
+                    vertex_weights: (0..*count as u32)
+                        .map(|vertex_index| VertexWeight {
+                            vertex_index,
+                            vertex_weight: 1.0,
+                        })
+                        .collect(),
+                }];
+                &parent_weights[..]
+            } else {
+                &object.bone_influences[..]
+            };
+
+            for influence in influences {
+                let weights = influence.vertex_weights.iter().map(|w| VertexWeight {
+                    vertex_index: w.vertex_index + offset,
+                    vertex_weight: w.vertex_weight,
+                });
+                match bone_influences
+                    .iter_mut()
+                    .find(|i| i.bone_name == influence.bone_name)
+                {
+                    Some(existing) => existing.vertex_weights.extend(weights),
+                    None => bone_influences.push(BoneInfluence {
+                        bone_name: influence.bone_name.clone(),
+                        vertex_weights: weights.collect(),
+                    }),
+                }
+            }
+        }
+    }
+
+    let merge = |get: fn(&MeshObjectData) -> &Vec<AttributeData>| {
+        merge_attributes(objects.iter().map(get).zip(counts.iter().copied()))
+    };
+
+    Ok(MeshObjectData {
+        name: first.name.clone(),
+        subindex: first.subindex,
+        parent_bone_name: if use_weights {
+            String::new()
+        } else {
+            first.parent_bone_name.clone()
+        },
+        disable_depth_test: first.disable_depth_test,
+        disable_depth_write: first.disable_depth_write,
+        sort_bias: first.sort_bias,
+        vertex_indices: objects
+            .iter()
+            .zip(&offsets)
+            .flat_map(|(o, offset)| o.vertex_indices.iter().map(move |i| i + offset))
+            .collect(),
+        positions: merge(|o| &o.positions),
+        normals: merge(|o| &o.normals),
+        binormals: merge(|o| &o.binormals),
+        tangents: merge(|o| &o.tangents),
+        texture_coordinates: merge(|o| &o.texture_coordinates),
+        color_sets: merge(|o| &o.color_sets),
+        bone_influences,
+    })
+}
+
+/// Assigns increasing subindices to objects with the same name in order.
+///
+/// If `modl` is set, the entries are recreated in the same order as the objects
+/// using the material from the entry for each object's previous name and subindex.
+/// Entries for mesh object names not in `mesh` are preserved.
+/// Objects with the same name and subindex as a previous object can't be matched to an entry,
+/// so this returns an error if `modl` has an entry for their name and subindex.
+pub fn update_subindices(mesh: &mut MeshData, modl: Option<&mut ModlData>) -> Result<(), String> {
+    if let Some(modl) = &modl {
+        for (i, object) in mesh.objects.iter().enumerate() {
+            let is_duplicate = mesh.objects[..i]
+                .iter()
+                .any(|o| o.name == object.name && o.subindex == object.subindex);
+            let has_entry = modl.entries.iter().any(|e| {
+                e.mesh_object_name == object.name && e.mesh_object_subindex == object.subindex
+            });
+            if is_duplicate && has_entry {
+                return Err(format!(
+                    "Multiple mesh objects named {:?} have subindex {}, so the modl entry for subindex {} is ambiguous.",
+                    object.name, object.subindex, object.subindex
+                ));
+            }
+        }
+    }
+
+    let mut previous = Vec::new();
+    let mut subindices: HashMap<String, u64> = HashMap::new();
+    for object in &mut mesh.objects {
+        previous.push(object.subindex);
+        let subindex = subindices.entry(object.name.clone()).or_default();
+        object.subindex = *subindex;
+        *subindex += 1;
+    }
+
+    if let Some(modl) = modl {
+        let mut entries: Vec<ModlEntryData> = mesh
+            .objects
+            .iter()
+            .zip(previous)
+            .filter_map(|(object, previous)| {
+                let entry = modl.entries.iter().find(|e| {
+                    e.mesh_object_name == object.name && e.mesh_object_subindex == previous
+                })?;
+                Some(ModlEntryData {
+                    mesh_object_name: object.name.clone(),
+                    mesh_object_subindex: object.subindex,
+                    material_label: entry.material_label.clone(),
+                })
+            })
+            .collect();
+        entries.extend(
+            modl.entries
+                .iter()
+                .filter(|e| !subindices.contains_key(&e.mesh_object_name))
+                .cloned(),
+        );
+        modl.entries = entries;
+    }
+    Ok(())
+}
+
+fn merge_attributes<'a>(
+    objects: impl Iterator<Item = (&'a Vec<AttributeData>, usize)> + Clone,
+) -> Vec<AttributeData> {
+    // Use the name and component count from the first occurrence of each attribute.
+    let mut names: Vec<(&str, usize)> = Vec::new();
+    for (attributes, _) in objects.clone() {
+        for a in attributes {
+            if !names.iter().any(|(n, _)| *n == a.name) {
+                names.push((&a.name, vector_components(&a.data).1));
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|(name, components)| {
+            let mut values = Vec::new();
+            for (attributes, count) in objects.clone() {
+                match attributes.iter().find(|a| a.name == name) {
+                    Some(a) => values.extend(resized_components(&a.data, components)),
+                    None => values.extend(std::iter::repeat_n(0.0, count * components)),
+                }
+            }
+            AttributeData {
+                name: name.to_string(),
+                data: vector_data(&values, components),
+            }
+        })
+        .collect()
+}
+
+fn resized_components(data: &VectorData, components: usize) -> Vec<f32> {
+    match components {
+        2 => vectors2(data).into_iter().flatten().collect(),
+        3 => vectors3(data).into_iter().flatten().collect(),
+        _ => vectors4(data).into_iter().flatten().collect(),
+    }
+}
+
+fn vector_data(values: &[f32], components: usize) -> VectorData {
+    match components {
+        2 => VectorData::Vector2(values.chunks_exact(2).map(|v| [v[0], v[1]]).collect()),
+        3 => VectorData::Vector3(values.chunks_exact(3).map(|v| [v[0], v[1], v[2]]).collect()),
+        _ => VectorData::Vector4(
+            values
+                .chunks_exact(4)
+                .map(|v| [v[0], v[1], v[2], v[3]])
+                .collect(),
+        ),
+    }
+}
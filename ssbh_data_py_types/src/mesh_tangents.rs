@@ -0,0 +1,200 @@
+use glam::{Vec2, Vec3, Vec4, Vec4Swizzles};
+use ssbh_data::mesh_data::{AttributeData, MeshObjectData, VectorData};
+
+use crate::mesh_cleanup::{append_vertex_copies, checked_vertex_count};
+use crate::vector_data::{vectors2, vectors3};
+
+/// The result of tangent space generation for a mesh object.
+#[derive(Debug, PartialEq)]
+pub struct TangentSpace {
+    /// The number of vertices added for vertices with different tangents for each face.
+    pub added_vertices: usize,
+    /// Problems with the input data that may cause incorrect tangents.
+    pub warnings: Vec<String>,
+}
+
+/// Calculates MikkTSpace tangents and binormals for `object` using the UV attribute named `uv_set`.
+///
+/// The results are stored in the "Tangent0" and "Binormal0" attributes.
+/// The tangent W component stores the sign of the binormal.
+/// Vertices shared by faces with different tangents are duplicated along with all their attributes and weights.
+pub fn generate_tangent_space(
+    object: &mut MeshObjectData,
+    uv_set: &str,
+) -> Result<TangentSpace, String> {
+    let count = checked_vertex_count(object)?;
+    if !object.vertex_indices.len().is_multiple_of(3) {
+        return Err(format!(
+            "Vertex index count {} for mesh object {:?} is not a multiple of 3.",
+            object.vertex_indices.len(),
+            object.name
+        ));
+    }
+
+    let positions = object
+        .positions
+        .first()
+        .map(|a| vectors3(&a.data))
+        .ok_or_else(|| format!("Mesh object {:?} has no positions.", object.name))?;
+    let normals = object
+        .normals
+        .first()
+        .map(|a| vectors3(&a.data))
+        .ok_or_else(|| format!("Mesh object {:?} has no normals.", object.name))?;
+    let uvs = object
+        .texture_coordinates
+        .iter()
+        .find(|a| a.name == uv_set)
+        .map(|a| vectors2(&a.data))
+        .ok_or_else(|| {
+            format!(
+                "Mesh object {:?} has no texture coordinates named {uv_set:?}.",
+                object.name
+            )
+        })?;
+
+    let mut warnings = Vec::new();
+    let degenerate_count = object
+        .vertex_indices
+        .chunks_exact(3)
+        .filter(|f| {
+            let [a, b, c] = [f[0], f[1], f[2]].map(|i| Vec2::from(uvs[i as usize]));
+            (b - a).perp_dot(c - a).abs() <= f32::EPSILON
+        })
+        .count();
+    if degenerate_count > 0 {
+        warnings.push(format!(
+            "Mesh object {:?} has {degenerate_count} triangles with zero area in {uv_set:?}. Tangents for these triangles may be incorrect.",
+            object.name
+        ));
+    }
+
+    let mut geometry = Geometry {
+        positions: &positions,
+        normals: &normals,
+        uvs: &uvs,
+        vertex_indices: &object.vertex_indices,
+        tangents: vec![Vec4::ZERO; object.vertex_indices.len()],
+    };
+    if !bevy_mikktspace::generate_tangents(&mut geometry) {
+        warnings.push(format!(
+            "Failed to generate tangents for mesh object {:?}. Using default tangents instead.",
+            object.name
+        ));
+    }
+
+    // MikkTSpace may skip degenerate faces, so use a tangent perpendicular to the normal.
+    let mut corner_tangents = geometry.tangents;
+    for (tangent, i) in corner_tangents.iter_mut().zip(&object.vertex_indices) {
+        if tangent.xyz() == Vec3::ZERO || !tangent.is_finite() {
+            let normal = Vec3::from(normals[*i as usize]).normalize_or(Vec3::Z);
+            *tangent = normal.any_orthonormal_vector().extend(1.0);
+        }
+    }
+
+    // Vertices can only store a single tangent.
+    let mut vertex_tangents: Vec<Vec<(Vec4, u32)>> = vec![Vec::new(); count];
+    let mut sources = Vec::new();
+    let mut vertex_indices = object.vertex_indices.clone();
+    for (v, tangent) in vertex_indices.iter_mut().zip(&corner_tangents) {
+        let tangents = &mut vertex_tangents[*v as usize];
+        match tangents.iter().find(|(t, _)| is_same_tangent(*t, *tangent)) {
+            Some((_, index)) => *v = *index,
+            None => {
+                let index = if tangents.is_empty() {
+                    *v
+                } else {
+                    sources.push(*v as usize);
+                    (count + sources.len() - 1) as u32
+                };
+                tangents.push((*tangent, index));
+                *v = index;
+            }
+        }
+    }
+
+    let mut tangents = vec![Vec4::ZERO; count + sources.len()];
+    let mut binormals = vec![Vec3::ZERO; count + sources.len()];
+    for (v, tangent) in vertex_indices.iter().zip(&corner_tangents) {
+        let source = match (*v as usize).checked_sub(count) {
+            Some(i) => sources[i],
+            None => *v as usize,
+        };
+        let normal = Vec3::from(normals[source]);
+        tangents[*v as usize] = *tangent;
+        binormals[*v as usize] = normal.cross(tangent.xyz()) * tangent.w;
+    }
+
+    append_vertex_copies(object, &sources);
+    object.vertex_indices = vertex_indices;
+    set_attribute(
+        &mut object.tangents,
+        "Tangent0",
+        VectorData::Vector4(tangents.iter().map(|t| t.to_array()).collect()),
+    );
+    set_attribute(
+        &mut object.binormals,
+        "Binormal0",
+        VectorData::Vector3(binormals.iter().map(|b| b.to_array()).collect()),
+    );
+
+    Ok(TangentSpace {
+        added_vertices: sources.len(),
+        warnings,
+    })
+}
+
+fn is_same_tangent(a: Vec4, b: Vec4) -> bool {
+    a.w.signum() == b.w.signum()
+        && a.xyz().normalize_or_zero().dot(b.xyz().normalize_or_zero()) > 0.9999
+}
+
+fn set_attribute(attributes: &mut Vec<AttributeData>, name: &str, data: VectorData) {
+    match attributes.iter_mut().find(|a| a.name == name) {
+        Some(attribute) => attribute.data = data,
+        None => attributes.push(AttributeData {
+            name: name.to_string(),
+            data,
+        }),
+    }
+}
+
+struct Geometry<'a> {
+    positions: &'a [[f32; 3]],
+    normals: &'a [[f32; 3]],
+    uvs: &'a [[f32; 2]],
+    vertex_indices: &'a [u32],
+    tangents: Vec<Vec4>,
+}
+
+impl Geometry<'_> {
+    fn index(&self, face: usize, vert: usize) -> usize {
+        self.vertex_indices[face * 3 + vert] as usize
+    }
+}
+
+impl bevy_mikktspace::Geometry for Geometry<'_> {
+    fn num_faces(&self) -> usize {
+        self.vertex_indices.len() / 3
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.positions[self.index(face, vert)]
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.normals[self.index(face, vert)]
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.uvs[self.index(face, vert)]
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        self.tangents[face * 3 + vert] = Vec4::from(tangent);
+    }
+}
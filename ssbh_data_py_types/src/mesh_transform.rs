@@ -0,0 +1,91 @@
+use glam::{Mat4, Vec3, Vec4};
+use ssbh_data::mesh_data::{MeshObjectData, VectorData};
+use ssbh_data::skel_data::SkelData;
+
+/// Transforms the positions, normals, binormals, and tangents of `object` by `transform`.
+///
+/// Normals use the inverse transpose to also support non uniform scale.
+/// The tangent W component is flipped for transforms that mirror the object.
+pub fn transform_object(object: &mut MeshObjectData, transform: Mat4) {
+    if transform == Mat4::IDENTITY {
+        return;
+    }
+
+    let normal_transform = transform.inverse().transpose();
+    let flip_w = transform.determinant() < 0.0;
+    for attribute in &mut object.positions {
+        transform_data(
+            &mut attribute.data,
+            |v| transform.transform_point3(v),
+            false,
+        );
+    }
+    for attribute in &mut object.normals {
+        transform_data(
+            &mut attribute.data,
+            |v| normal_transform.transform_vector3(v).normalize_or_zero(),
+            false,
+        );
+    }
+    for attribute in &mut object.binormals {
+        transform_data(
+            &mut attribute.data,
+            |v| transform.transform_vector3(v).normalize_or_zero(),
+            false,
+        );
+    }
+    for attribute in &mut object.tangents {
+        transform_data(
+            &mut attribute.data,
+            |v| transform.transform_vector3(v).normalize_or_zero(),
+            flip_w,
+        );
+    }
+}
+
+/// Finds the world transform for the bone named `name` in `skel`.
+pub fn bone_world_transform(skel: &SkelData, name: &str) -> Result<Mat4, String> {
+    let bone = skel
+        .bones
+        .iter()
+        .find(|b| b.name == name)
+        .ok_or_else(|| format!("Bone {name:?} not found in the skeleton."))?;
+    skel.calculate_world_transform(bone)
+        .map(|t| Mat4::from_cols_array_2d(&t))
+        .map_err(|e| format!("{e}"))
+}
+
+/// Transforms `object` from the space of its parent bone to model space.
+/// Objects without a parent bone or with vertex skinning are already in model space.
+pub fn parent_bone_to_model_space(
+    object: &mut MeshObjectData,
+    skel: &SkelData,
+) -> Result<(), String> {
+    if object.bone_influences.is_empty() && !object.parent_bone_name.is_empty() {
+        let transform = bone_world_transform(skel, &object.parent_bone_name)?;
+        transform_object(object, transform);
+    }
+    Ok(())
+}
+
+fn transform_data(data: &mut VectorData, f: impl Fn(Vec3) -> Vec3, flip_w: bool) {
+    match data {
+        VectorData::Vector2(values) => {
+            for v in values {
+                let [x, y, _] = f(Vec3::new(v[0], v[1], 0.0)).to_array();
+                *v = [x, y];
+            }
+        }
+        VectorData::Vector3(values) => {
+            for v in values {
+                *v = f(Vec3::from_array(*v)).to_array();
+            }
+        }
+        VectorData::Vector4(values) => {
+            for v in values {
+                let w = if flip_w { -v[3] } else { v[3] };
+                *v = Vec4::from((f(Vec3::new(v[0], v[1], v[2])), w)).to_array();
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::{Mat2, Vec2};
+use ssbh_data::matl_data::UvTransform;
+use ssbh_data::mesh_data::{AttributeData, MeshObjectData, VectorData};
+
+use crate::mesh_cleanup::checked_vertex_count;
+use crate::mesh_split::{find, union};
+use crate::vector_data::{vectors2, vectors3};
+
+/// Flips the V coordinate of the UV set named `uv_set` so that `v` becomes `1.0 - v`.
+pub fn flip_v(object: &mut MeshObjectData, uv_set: &str) -> Result<(), String> {
+    let attribute = uv_set_mut(object, uv_set)?;
+    map_uvs(&mut attribute.data, |uv| Vec2::new(uv.x, 1.0 - uv.y));
+    Ok(())
+}
+
+/// Applies `transform` to the UV set named `uv_set`.
+///
+/// UVs are scaled, rotated counterclockwise about the origin by `rotation` in radians,
+/// and then translated.
+pub fn transform_uvs(
+    object: &mut MeshObjectData,
+    uv_set: &str,
+    transform: &UvTransform,
+) -> Result<(), String> {
+    let scale = Vec2::new(transform.scale_u, transform.scale_v);
+    let rotation = Mat2::from_angle(transform.rotation);
+    let translation = Vec2::new(transform.translate_u, transform.translate_v);
+
+    let attribute = uv_set_mut(object, uv_set)?;
+    map_uvs(&mut attribute.data, |uv| {
+        rotation * (uv * scale) + translation
+    });
+    Ok(())
+}
+
+/// Copies the UV set named `uv_set` to a UV set named `name`.
+/// The values for an existing UV set named `name` are replaced.
+pub fn copy_uv_set(object: &mut MeshObjectData, uv_set: &str, name: &str) -> Result<(), String> {
+    let data = uv_set_ref(object, uv_set)?.data.clone();
+    match object
+        .texture_coordinates
+        .iter_mut()
+        .find(|a| a.name == name)
+    {
+        Some(attribute) => attribute.data = data,
+        None => object.texture_coordinates.push(AttributeData {
+            name: name.to_string(),
+            data,
+        }),
+    }
+    Ok(())
+}
+
+/// Renames the UV set named `uv_set` to `name`.
+pub fn rename_uv_set(object: &mut MeshObjectData, uv_set: &str, name: &str) -> Result<(), String> {
+    if uv_set != name && object.texture_coordinates.iter().any(|a| a.name == name) {
+        return Err(format!(
+            "Mesh object {:?} already has texture coordinates named {name:?}.",
+            object.name
+        ));
+    }
+    uv_set_mut(object, uv_set)?.name = name.to_string();
+    Ok(())
+}
+
+/// Calculates the minimum and maximum UV values for the UV set named `uv_set`.
+pub fn uv_bounds(object: &MeshObjectData, uv_set: &str) -> Result<(Vec2, Vec2), String> {
+    let uvs = vectors2(&uv_set_ref(object, uv_set)?.data);
+    if uvs.is_empty() {
+        return Ok((Vec2::ZERO, Vec2::ZERO));
+    }
+
+    Ok(uvs.iter().fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), uv| (min.min(Vec2::from(*uv)), max.max(Vec2::from(*uv))),
+    ))
+}
+
+/// Counts the groups of triangles connected in the UV set named `uv_set`.
+///
+/// Triangles are connected if they share a vertex
+/// or a vertex with the same position and UV value.
+pub fn uv_island_count(object: &MeshObjectData, uv_set: &str) -> Result<usize, String> {
+    let count = checked_vertex_count(object)?;
+    let uvs = vectors2(&uv_set_ref(object, uv_set)?.data);
+    let positions = object
+        .positions
+        .first()
+        .map(|p| vectors3(&p.data))
+        .unwrap_or_default();
+
+    let mut parents: Vec<usize> = (0..count).collect();
+
+    // Vertices may be duplicated for hard edges without splitting the UVs.
+    let mut vertices: HashMap<([u32; 3], [u32; 2]), usize> = HashMap::new();
+    for (i, (p, uv)) in positions.iter().zip(&uvs).enumerate() {
+        let key = (p.map(|f| f.to_bits()), uv.map(|f| f.to_bits()));
+        match vertices.get(&key) {
+            Some(j) => union(&mut parents, *j, i),
+            None => {
+                vertices.insert(key, i);
+            }
+        }
+    }
+    for face in object.vertex_indices.chunks_exact(3) {
+        union(&mut parents, face[0] as usize, face[1] as usize);
+        union(&mut parents, face[0] as usize, face[2] as usize);
+    }
+
+    let islands: HashSet<_> = object
+        .vertex_indices
+        .chunks_exact(3)
+        .map(|face| find(&mut parents, face[0] as usize))
+        .collect();
+    Ok(islands.len())
+}
+
+fn uv_set_ref<'a>(object: &'a MeshObjectData, uv_set: &str) -> Result<&'a AttributeData, String> {
+    object
+        .texture_coordinates
+        .iter()
+        .find(|a| a.name == uv_set)
+        .ok_or_else(|| missing_uv_set(object, uv_set))
+}
+
+fn uv_set_mut<'a>(
+    object: &'a mut MeshObjectData,
+    uv_set: &str,
+) -> Result<&'a mut AttributeData, String> {
+    let error = missing_uv_set(object, uv_set);
+    object
+        .texture_coordinates
+        .iter_mut()
+        .find(|a| a.name == uv_set)
+        .ok_or(error)
+}
+
+fn missing_uv_set(object: &MeshObjectData, uv_set: &str) -> String {
+    let names: Vec<_> = object
+        .texture_coordinates
+        .iter()
+        .map(|a| a.name.as_str())
+        .collect();
+    format!(
+        "Mesh object {:?} has no texture coordinates named {uv_set:?}. Expected one of {names:?}.",
+        object.name
+    )
+}
+
+fn map_uvs(data: &mut VectorData, f: impl Fn(Vec2) -> Vec2) {
+    match data {
+        VectorData::Vector2(values) => {
+            for v in values {
+                *v = f(Vec2::from(*v)).to_array();
+            }
+        }
+        VectorData::Vector3(values) => {
+            for v in values {
+                [v[0], v[1]] = f(Vec2::new(v[0], v[1])).to_array();
+            }
+        }
+        VectorData::Vector4(values) => {
+            for v in values {
+                [v[0], v[1]] = f(Vec2::new(v[0], v[1])).to_array();
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ create_exception!(ssbh_data_py, MeshExDataError, pyo3::exceptions::PyException);
 pub mod meshex_data {
     pub use super::*;
 
-    use crate::mesh_data::mesh_data::MeshObjectData;
+    use crate::mesh_data::mesh_data::{MeshData, MeshObjectData};
     use crate::{map_from_vector3, map_into_vector3, PyInit, PyRepr, Pyi, PyiMethods};
     use map_py::{MapPy, TypedList};
 
@@ -47,11 +47,70 @@ pub mod meshex_data {
                 .map_err(PyErr::from)
         }
 
+        fn update_from_mesh(&mut self, py: Python, mesh: MeshData) -> PyResult<()> {
+            let mut meshex = self.clone().map_py(py)?;
+            crate::meshex_update::update_from_mesh(&mut meshex, &mesh.map_py(py)?);
+            *self = meshex.map_py(py)?;
+            Ok(())
+        }
+
+        fn find_group(&self, py: Python, name: &str) -> PyResult<Option<Py<MeshObjectGroupData>>> {
+            for group in self.mesh_object_groups.list.bind(py).iter() {
+                let group = group.downcast::<MeshObjectGroupData>()?;
+                let is_match = {
+                    let group = group.borrow();
+                    group.mesh_object_full_name == name || group.mesh_object_name == name
+                };
+                if is_match {
+                    return Ok(Some(group.clone().unbind()));
+                }
+            }
+            Ok(None)
+        }
+
+        #[pyo3(signature = (name, cast_shadow, subindex = None))]
+        fn set_cast_shadow(
+            &mut self,
+            py: Python,
+            name: &str,
+            cast_shadow: bool,
+            subindex: Option<usize>,
+        ) -> PyResult<()> {
+            self.edit_entry_flags(py, name, subindex, |f| f.cast_shadow = cast_shadow)
+        }
+
+        #[pyo3(signature = (name, draw_model, subindex = None))]
+        fn set_draw_model(
+            &mut self,
+            py: Python,
+            name: &str,
+            draw_model: bool,
+            subindex: Option<usize>,
+        ) -> PyResult<()> {
+            self.edit_entry_flags(py, name, subindex, |f| f.draw_model = draw_model)
+        }
+
         fn __repr__(&self) -> String {
             self.py_repr()
         }
     }
 
+    impl MeshExData {
+        fn edit_entry_flags(
+            &mut self,
+            py: Python,
+            name: &str,
+            subindex: Option<usize>,
+            edit: impl Fn(&mut ssbh_data::meshex_data::EntryFlags),
+        ) -> PyResult<()> {
+            let mut meshex = self.clone().map_py(py)?;
+            crate::meshex_update::edit_entry_flags(&mut meshex, name, subindex, edit)
+                .map_err(MeshExDataError::new_err)?;
+            *self = meshex.map_py(py)?;
+            Ok(())
+        }
+    }
+
     // TODO: Can we document the actual default value here?
     // Add the default to some sort of derive attribute?
     impl PyiMethods for MeshExData {
@@ -61,7 +120,25 @@ pub mod meshex_data {
     @staticmethod
     def from_mesh_objects(objects: list[MeshObjectData]) -> MeshExData: ...
     
-    def save(self, path: str) -> None: ..."
+    def save(self, path: str) -> None: ...
+
+    def update_from_mesh(self, mesh: MeshData) -> None: ...
+
+    def find_group(self, name: str) -> Optional[MeshObjectGroupData]: ...
+
+    def set_cast_shadow(
+        self,
+        name: str,
+        cast_shadow: bool,
+        subindex: Optional[int] = None,
+    ) -> None: ...
+
+    def set_draw_model(
+        self,
+        name: str,
+        draw_model: bool,
+        subindex: Optional[int] = None,
+    ) -> None: ..."
                 .to_string()
         }
     }
@@ -0,0 +1,70 @@
+use ssbh_data::mesh_data::MeshData;
+use ssbh_data::meshex_data::{EntryFlags, MeshExData};
+
+/// Recalculates the groups and bounding spheres in `meshex` from the objects in `mesh`.
+///
+/// The entry flags are preserved for groups with the same `mesh_object_full_name`.
+/// New groups or entries use the defaults from [MeshExData::from_mesh_objects],
+/// and groups for objects no longer in `mesh` are removed.
+pub fn update_from_mesh(meshex: &mut MeshExData, mesh: &MeshData) {
+    let mut updated = MeshExData::from_mesh_objects(&mesh.objects);
+    for group in &mut updated.mesh_object_groups {
+        if let Some(previous) = meshex
+            .mesh_object_groups
+            .iter()
+            .find(|g| g.mesh_object_full_name == group.mesh_object_full_name)
+        {
+            for (flags, previous) in group.entry_flags.iter_mut().zip(&previous.entry_flags) {
+                flags.draw_model = previous.draw_model;
+                flags.cast_shadow = previous.cast_shadow;
+            }
+        }
+    }
+    *meshex = updated;
+}
+
+/// Applies `edit` to the entry flags for groups matching `name`
+/// and returns the number of changed groups.
+///
+/// The `name` can be either the `mesh_object_full_name` or the shorter `mesh_object_name`.
+/// If `subindex` is set, only the flags for that entry in each group are changed.
+pub fn edit_entry_flags(
+    meshex: &mut MeshExData,
+    name: &str,
+    subindex: Option<usize>,
+    edit: impl Fn(&mut EntryFlags),
+) -> Result<usize, String> {
+    let mut count = 0;
+    for group in meshex
+        .mesh_object_groups
+        .iter_mut()
+        .filter(|g| g.mesh_object_full_name == name || g.mesh_object_name == name)
+    {
+        match subindex {
+            Some(i) => {
+                let entry_count = group.entry_flags.len();
+                let flags = group.entry_flags.get_mut(i).ok_or_else(|| {
+                    format!(
+                        "Subindex {i} is out of range for mesh object group {:?} with {entry_count} entries.",
+                        group.mesh_object_full_name
+                    )
+                })?;
+                edit(flags);
+            }
+            None => group.entry_flags.iter_mut().for_each(&edit),
+        }
+        count += 1;
+    }
+
+    if count == 0 {
+        let names: Vec<_> = meshex
+            .mesh_object_groups
+            .iter()
+            .map(|g| g.mesh_object_full_name.as_str())
+            .collect();
+        return Err(format!(
+            "No mesh object group found for {name:?}. Expected one of {names:?}."
+        ));
+    }
+    Ok(count)
+}
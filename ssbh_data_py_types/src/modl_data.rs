@@ -49,6 +49,21 @@ pub mod modl_data {
                 .map_err(PyErr::from)
         }
 
+        fn convert_version(
+            &mut self,
+            py: Python,
+            major_version: u16,
+            minor_version: u16,
+        ) -> PyResult<Vec<String>> {
+            let data = self.clone().map_py(py)?;
+            let changes =
+                crate::version_convert::convert_version(&data, (major_version, minor_version))
+                    .map_err(ModlDataError::new_err)?;
+            self.major_version = major_version;
+            self.minor_version = minor_version;
+            Ok(changes)
+        }
+
         fn __repr__(&self) -> String {
             self.py_repr()
         }
@@ -64,7 +79,13 @@ pub mod modl_data {
         minor_version: int = 7,
     ) -> None: ...
     
-    def save(self, path: str) -> None: ..."#
+    def save(self, path: str) -> None: ...
+
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ..."#
                 .to_string()
         }
     }
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use ssbh_data::mesh_data::{AttributeData, MeshData, MeshObjectData, VectorData};
+
+use crate::vector_data::{vectors2, vectors3};
+
+/// Writes the positions, normals, first UV set, and faces for each object in `mesh` to an OBJ file.
+///
+/// Each object is written as a separate group using the object's name.
+/// The V coordinate is flipped to match the bottom left UV origin expected by OBJ.
+pub fn write_obj(mesh: &MeshData) -> Result<String, String> {
+    let mut obj = String::new();
+
+    // OBJ indices start from 1 and are shared by all groups.
+    let mut position_offset = 1;
+    let mut uv_offset = 1;
+    let mut normal_offset = 1;
+    for object in &mesh.objects {
+        let positions = object
+            .positions
+            .first()
+            .map(|a| vectors3(&a.data))
+            .unwrap_or_default();
+        let normals = object.normals.first().map(|a| vectors3(&a.data));
+        let uvs = object
+            .texture_coordinates
+            .first()
+            .map(|a| vectors2(&a.data));
+
+        for (name, count) in [
+            ("normals", normals.as_ref().map(|v| v.len())),
+            ("texture coordinates", uvs.as_ref().map(|v| v.len())),
+        ] {
+            if let Some(count) = count.filter(|c| *c != positions.len()) {
+                return Err(format!(
+                    "Mesh object {:?} has {} positions but {count} {name}.",
+                    object.name,
+                    positions.len()
+                ));
+            }
+        }
+        if let Some(i) = object
+            .vertex_indices
+            .iter()
+            .find(|i| **i as usize >= positions.len())
+        {
+            return Err(format!(
+                "Vertex index {i} is out of range for mesh object {:?} with {} vertices.",
+                object.name,
+                positions.len()
+            ));
+        }
+
+        writeln!(&mut obj, "g {}", object.name).unwrap();
+        for [x, y, z] in &positions {
+            writeln!(&mut obj, "v {x} {y} {z}").unwrap();
+        }
+        for [u, v] in uvs.iter().flatten() {
+            writeln!(&mut obj, "vt {u} {}", 1.0 - v).unwrap();
+        }
+        for [x, y, z] in normals.iter().flatten() {
+            writeln!(&mut obj, "vn {x} {y} {z}").unwrap();
+        }
+
+        for face in object.vertex_indices.chunks_exact(3) {
+            let vertices: Vec<_> = face
+                .iter()
+                .map(|i| {
+                    let i = *i as usize;
+                    match (uvs.is_some(), normals.is_some()) {
+                        (true, true) => format!(
+                            "{}/{}/{}",
+                            position_offset + i,
+                            uv_offset + i,
+                            normal_offset + i
+                        ),
+                        (true, false) => format!("{}/{}", position_offset + i, uv_offset + i),
+                        (false, true) => format!("{}//{}", position_offset + i, normal_offset + i),
+                        (false, false) => format!("{}", position_offset + i),
+                    }
+                })
+                .collect();
+            writeln!(&mut obj, "f {}", vertices.join(" ")).unwrap();
+        }
+
+        position_offset += positions.len();
+        uv_offset += uvs.map(|v| v.len()).unwrap_or_default();
+        normal_offset += normals.map(|v| v.len()).unwrap_or_default();
+    }
+
+    Ok(obj)
+}
+
+/// Creates a mesh object for each group or object in the OBJ file text `obj`.
+///
+/// Polygons are triangulated and vertices are split if the position, UV, or normal indices differ.
+/// Repeated group names are assigned increasing subindices.
+pub fn read_obj(obj: &str) -> Result<MeshData, String> {
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    let mut normals = Vec::new();
+
+    let mut objects = Vec::new();
+    let mut current: Option<ObjObject> = None;
+
+    for (line_index, line) in obj.lines().enumerate() {
+        let line_number = line_index + 1;
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("v") => positions.push(parse_floats::<3>(parts, line_number)?),
+            Some("vt") => {
+                // The v coordinate is optional and defaults to 0.
+                let mut parts = parts.peekable();
+                let [u] = parse_floats::<1>(&mut parts, line_number)?;
+                let [v] = match parts.peek() {
+                    Some(_) => parse_floats::<1>(parts, line_number)?,
+                    None => [0.0],
+                };
+                uvs.push([u, 1.0 - v]);
+            }
+            Some("vn") => normals.push(parse_floats::<3>(parts, line_number)?),
+            Some("g") | Some("o") => {
+                let name = parts.collect::<Vec<_>>().join(" ");
+                objects.extend(current.take());
+                current = Some(ObjObject::new(name));
+            }
+            Some("f") => {
+                let object = current.get_or_insert_with(|| ObjObject::new(String::new()));
+                let face = parts
+                    .map(|v| {
+                        parse_face_vertex(v, positions.len(), uvs.len(), normals.len()).ok_or_else(
+                            || format!("Invalid face vertex {v:?} on line {line_number}."),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if face.len() < 3 {
+                    return Err(format!(
+                        "Expected at least 3 vertices for face on line {line_number}."
+                    ));
+                }
+
+                // Triangulate polygons as a triangle fan.
+                for i in 1..face.len() - 1 {
+                    for vertex in [face[0], face[i], face[i + 1]] {
+                        object.add_vertex(vertex);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    objects.extend(current);
+
+    let mut subindices: HashMap<String, u64> = HashMap::new();
+    let objects = objects
+        .into_iter()
+        .filter(|o| !o.vertex_indices.is_empty())
+        .map(|o| {
+            let subindex = subindices.entry(o.name.clone()).or_default();
+            let object = o.mesh_object(*subindex, &positions, &uvs, &normals);
+            *subindex += 1;
+            object
+        })
+        .collect();
+
+    Ok(MeshData {
+        major_version: 1,
+        minor_version: 10,
+        objects,
+    })
+}
+
+/// Indices for the position, UV, and normal of a face vertex.
+type FaceVertex = (usize, Option<usize>, Option<usize>);
+
+struct ObjObject {
+    name: String,
+    vertices: Vec<FaceVertex>,
+    vertex_indices: Vec<u32>,
+    vertex_lookup: HashMap<FaceVertex, u32>,
+}
+
+impl ObjObject {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            vertices: Vec::new(),
+            vertex_indices: Vec::new(),
+            vertex_lookup: HashMap::new(),
+        }
+    }
+
+    fn add_vertex(&mut self, vertex: FaceVertex) {
+        let index = *self.vertex_lookup.entry(vertex).or_insert_with(|| {
+            self.vertices.push(vertex);
+            self.vertices.len() as u32 - 1
+        });
+        self.vertex_indices.push(index);
+    }
+
+    fn mesh_object(
+        self,
+        subindex: u64,
+        positions: &[[f32; 3]],
+        uvs: &[[f32; 2]],
+        normals: &[[f32; 3]],
+    ) -> MeshObjectData {
+        let has_uvs = self.vertices.iter().any(|(_, uv, _)| uv.is_some());
+        let has_normals = self.vertices.iter().any(|(_, _, n)| n.is_some());
+
+        MeshObjectData {
+            name: self.name,
+            subindex,
+            parent_bone_name: String::new(),
+            disable_depth_test: false,
+            disable_depth_write: false,
+            sort_bias: 0,
+            vertex_indices: self.vertex_indices,
+            positions: vec![AttributeData {
+                name: "Position0".to_string(),
+                data: VectorData::Vector3(self.vertices.iter().map(|v| positions[v.0]).collect()),
+            }],
+            normals: if has_normals {
+                vec![AttributeData {
+                    name: "Normal0".to_string(),
+                    data: VectorData::Vector3(
+                        self.vertices
+                            .iter()
+                            .map(|v| v.2.map(|i| normals[i]).unwrap_or_default())
+                            .collect(),
+                    ),
+                }]
+            } else {
+                Vec::new()
+            },
+            binormals: Vec::new(),
+            tangents: Vec::new(),
+            texture_coordinates: if has_uvs {
+                vec![AttributeData {
+                    name: "map1".to_string(),
+                    data: VectorData::Vector2(
+                        self.vertices
+                            .iter()
+                            .map(|v| v.1.map(|i| uvs[i]).unwrap_or_default())
+                            .collect(),
+                    ),
+                }]
+            } else {
+                Vec::new()
+            },
+            color_sets: Vec::new(),
+            bone_influences: Vec::new(),
+        }
+    }
+}
+
+fn parse_floats<'a, const N: usize>(
+    parts: impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<[f32; N], String> {
+    let mut values = [0.0; N];
+    let mut parts = parts;
+    for value in &mut values {
+        *value = parts
+            .next()
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(|| format!("Expected {N} values on line {line_number}."))?;
+    }
+    Ok(values)
+}
+
+fn parse_face_vertex(
+    vertex: &str,
+    position_count: usize,
+    uv_count: usize,
+    normal_count: usize,
+) -> Option<FaceVertex> {
+    let mut indices = vertex.split('/');
+    let position = parse_index(indices.next()?, position_count)?;
+    let uv = match indices.next() {
+        Some("") | None => None,
+        Some(i) => Some(parse_index(i, uv_count)?),
+    };
+    let normal = match indices.next() {
+        Some("") | None => None,
+        Some(i) => Some(parse_index(i, normal_count)?),
+    };
+    Some((position, uv, normal))
+}
+
+// Convert 1-based or negative relative indices to 0-based indices.
+fn parse_index(index: &str, count: usize) -> Option<usize> {
+    let index: isize = index.parse().ok()?;
+    let index = if index < 0 {
+        count.checked_sub(index.unsigned_abs())?
+    } else {
+        (index as usize).checked_sub(1)?
+    };
+    (index < count).then_some(index)
+}
@@ -90,6 +90,12 @@ pub mod skel_data {
 
     def save(self, path: str) -> None: ...
 
+    def convert_version(
+        self,
+        major_version: int,
+        minor_version: int,
+    ) -> list[str]: ...
+
     def calculate_world_transform(
         self, bone: BoneData) -> numpy.ndarray: ..."#
                 .to_string()
@@ -115,6 +121,21 @@ pub mod skel_data {
                 .map_err(|e| SkelDataError::new_err(format!("{e}")))
         }
 
+        fn convert_version(
+            &mut self,
+            py: Python,
+            major_version: u16,
+            minor_version: u16,
+        ) -> PyResult<Vec<String>> {
+            let data = self.clone().map_py(py)?;
+            let changes =
+                crate::version_convert::convert_version(&data, (major_version, minor_version))
+                    .map_err(SkelDataError::new_err)?;
+            self.major_version = major_version;
+            self.minor_version = minor_version;
+            Ok(changes)
+        }
+
         fn calculate_world_transform(
             &self,
             py: Python,
@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ssbh_data::mesh_data::{BoneInfluence, MeshObjectData, VertexWeight};
+
+use crate::vector_data::vector_count;
+
+/// Bone indices and weights for each vertex with `influence_count` values per vertex.
+/// Unused values have a bone index and weight of 0.
+#[derive(Debug, PartialEq)]
+pub struct WeightArrays {
+    pub indices: Vec<u32>,
+    pub weights: Vec<f32>,
+    pub influence_count: usize,
+    pub bone_names: Vec<String>,
+}
+
+/// Converts `influences` to bone indices and weights sorted from highest to lowest weight for each vertex.
+///
+/// The influence count is the largest number of influences for any vertex.
+/// If `max_influences` is set, the influences with the smallest weights are removed.
+/// Weights are not normalized.
+pub fn weight_arrays(
+    influences: &[BoneInfluence],
+    vertex_count: usize,
+    max_influences: Option<usize>,
+) -> Result<WeightArrays, String> {
+    let (vertex_influences, bone_names) = vertex_influences(influences, vertex_count)?;
+
+    let influence_count = vertex_influences
+        .iter()
+        .map(|v| v.len())
+        .max()
+        .unwrap_or_default();
+    let influence_count = max_influences
+        .map(|m| m.min(influence_count))
+        .unwrap_or(influence_count);
+
+    let mut indices = vec![0; vertex_count * influence_count];
+    let mut weights = vec![0.0; vertex_count * influence_count];
+    for (i, mut values) in vertex_influences.into_iter().enumerate() {
+        values.sort_by(|a, b| b.1.total_cmp(&a.1));
+        for (j, (bone, weight)) in values.into_iter().take(influence_count).enumerate() {
+            indices[i * influence_count + j] = bone as u32;
+            weights[i * influence_count + j] = weight;
+        }
+    }
+
+    Ok(WeightArrays {
+        indices,
+        weights,
+        influence_count,
+        bone_names,
+    })
+}
+
+/// Converts `influences` to a row major matrix with a row for each vertex and a column for each bone name.
+pub fn weight_matrix(
+    influences: &[BoneInfluence],
+    vertex_count: usize,
+) -> Result<(Vec<f32>, Vec<String>), String> {
+    let (vertex_influences, bone_names) = vertex_influences(influences, vertex_count)?;
+
+    let mut matrix = vec![0.0; vertex_count * bone_names.len()];
+    for (i, values) in vertex_influences.into_iter().enumerate() {
+        for (bone, weight) in values {
+            matrix[i * bone_names.len() + bone] += weight;
+        }
+    }
+    Ok((matrix, bone_names))
+}
+
+/// Creates influences from bone indices and weights with `influence_count` values per vertex.
+/// Values with a weight of 0 are skipped.
+pub fn influences_from_arrays(
+    indices: &[u32],
+    weights: &[f32],
+    influence_count: usize,
+    bone_names: &[String],
+) -> Result<Vec<BoneInfluence>, String> {
+    if indices.len() != weights.len() {
+        return Err(format!(
+            "Expected the same shape for indices and weights but found {} and {} values.",
+            indices.len(),
+            weights.len()
+        ));
+    }
+
+    let mut bone_weights = vec![Vec::new(); bone_names.len()];
+    for (i, (vertex_indices, vertex_weights)) in indices
+        .chunks_exact(influence_count.max(1))
+        .zip(weights.chunks_exact(influence_count.max(1)))
+        .enumerate()
+    {
+        for (bone, weight) in vertex_indices.iter().zip(vertex_weights) {
+            if *weight != 0.0 {
+                let bone_weights = bone_weights.get_mut(*bone as usize).ok_or_else(|| {
+                    format!(
+                        "Bone index {bone} for vertex {i} is out of range for {} bone names.",
+                        bone_names.len()
+                    )
+                })?;
+                bone_weights.push(VertexWeight {
+                    vertex_index: i as u32,
+                    vertex_weight: *weight,
+                });
+            }
+        }
+    }
+    Ok(bone_influences(bone_names, bone_weights))
+}
+
+/// Creates influences from a row major matrix with a row for each vertex and a column for each bone name.
+/// Values with a weight of 0 are skipped.
+pub fn influences_from_matrix(
+    matrix: &[f32],
+    column_count: usize,
+    bone_names: &[String],
+) -> Result<Vec<BoneInfluence>, String> {
+    if column_count != bone_names.len() {
+        return Err(format!(
+            "Expected {} columns to match the bone names but found {column_count}.",
+            bone_names.len()
+        ));
+    }
+    if bone_names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut bone_weights = vec![Vec::new(); bone_names.len()];
+    for (i, row) in matrix.chunks_exact(bone_names.len()).enumerate() {
+        for (bone_weights, weight) in bone_weights.iter_mut().zip(row) {
+            if *weight != 0.0 {
+                bone_weights.push(VertexWeight {
+                    vertex_index: i as u32,
+                    vertex_weight: *weight,
+                });
+            }
+        }
+    }
+    Ok(bone_influences(bone_names, bone_weights))
+}
+
+/// The number of vertices for `object` based on the positions or the largest vertex index if there are no positions.
+pub fn vertex_count(object: &MeshObjectData) -> usize {
+    match object.positions.first() {
+        Some(positions) => vector_count(&positions.data),
+        None => object
+            .vertex_indices
+            .iter()
+            .map(|i| *i as usize + 1)
+            .max()
+            .unwrap_or_default(),
+    }
+}
+
+// Find the bone index and weight for each vertex.
+// Influences with the same bone name are combined.
+#[allow(clippy::type_complexity)]
+fn vertex_influences(
+    influences: &[BoneInfluence],
+    vertex_count: usize,
+) -> Result<(Vec<Vec<(usize, f32)>>, Vec<String>), String> {
+    let mut bone_names: Vec<String> = Vec::new();
+    let mut vertex_influences = vec![Vec::new(); vertex_count];
+    for influence in influences {
+        let bone = match bone_names.iter().position(|n| n == &influence.bone_name) {
+            Some(bone) => bone,
+            None => {
+                bone_names.push(influence.bone_name.clone());
+                bone_names.len() - 1
+            }
+        };
+
+        for w in &influence.vertex_weights {
+            let values: &mut Vec<(usize, f32)> = vertex_influences
+                .get_mut(w.vertex_index as usize)
+                .ok_or_else(|| {
+                    format!(
+                        "Vertex index {} for bone {:?} is out of range for {vertex_count} vertices.",
+                        w.vertex_index, influence.bone_name
+                    )
+                })?;
+            match values.iter_mut().find(|(b, _)| *b == bone) {
+                Some((_, weight)) => *weight += w.vertex_weight,
+                None => values.push((bone, w.vertex_weight)),
+            }
+        }
+    }
+    Ok((vertex_influences, bone_names))
+}
+
+fn bone_influences(
+    bone_names: &[String],
+    bone_weights: Vec<Vec<VertexWeight>>,
+) -> Vec<BoneInfluence> {
+    bone_names
+        .iter()
+        .zip(bone_weights)
+        .filter(|(_, w)| !w.is_empty())
+        .map(|(name, vertex_weights)| BoneInfluence {
+            bone_name: name.clone(),
+            vertex_weights,
+        })
+        .collect()
+}
+
+/// Scales the weights for each vertex to sum to 1.0 and returns the indices of the changed vertices.
+/// Vertices with no weights or weights summing to 0.0 are not changed.
+pub fn normalize_weights(influences: &mut [BoneInfluence]) -> Vec<u32> {
+    let mut sums: BTreeMap<u32, f32> = BTreeMap::new();
+    for w in influences.iter().flat_map(|i| &i.vertex_weights) {
+        *sums.entry(w.vertex_index).or_default() += w.vertex_weight;
+    }
+
+    let scales: BTreeMap<u32, f32> = sums
+        .into_iter()
+        .filter(|(_, sum)| *sum != 0.0 && (sum - 1.0).abs() > 1e-6)
+        .map(|(i, sum)| (i, 1.0 / sum))
+        .collect();
+    for w in influences.iter_mut().flat_map(|i| &mut i.vertex_weights) {
+        if let Some(scale) = scales.get(&w.vertex_index) {
+            w.vertex_weight *= scale;
+        }
+    }
+    scales.into_keys().collect()
+}
+
+/// Removes weights less than `threshold` and returns the indices of the changed vertices.
+/// Weights are not normalized.
+pub fn prune_weights(influences: &mut [BoneInfluence], threshold: f32) -> Vec<u32> {
+    let mut changed = BTreeSet::new();
+    for influence in influences {
+        influence.vertex_weights.retain(|w| {
+            let keep = w.vertex_weight >= threshold;
+            if !keep {
+                changed.insert(w.vertex_index);
+            }
+            keep
+        });
+    }
+    changed.into_iter().collect()
+}
+
+/// Removes the smallest weights for vertices with more than `max_influences` weights
+/// and returns the indices of the changed vertices.
+/// Weights are not normalized.
+pub fn limit_influences(influences: &mut [BoneInfluence], max_influences: usize) -> Vec<u32> {
+    // Find the influence and weight index for each vertex.
+    let mut vertex_weights: BTreeMap<u32, Vec<(usize, usize, f32)>> = BTreeMap::new();
+    for (i, influence) in influences.iter().enumerate() {
+        for (j, w) in influence.vertex_weights.iter().enumerate() {
+            vertex_weights
+                .entry(w.vertex_index)
+                .or_default()
+                .push((i, j, w.vertex_weight));
+        }
+    }
+
+    let mut removed: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); influences.len()];
+    let mut changed = Vec::new();
+    for (vertex_index, mut weights) in vertex_weights {
+        if weights.len() > max_influences {
+            weights.sort_by(|a, b| b.2.total_cmp(&a.2));
+            for (i, j, _) in &weights[max_influences..] {
+                removed[*i].insert(*j);
+            }
+            changed.push(vertex_index);
+        }
+    }
+
+    for (influence, removed) in influences.iter_mut().zip(removed) {
+        let mut j = 0;
+        influence.vertex_weights.retain(|_| {
+            let keep = !removed.contains(&j);
+            j += 1;
+            keep
+        });
+    }
+    changed
+}
+
+/// Removes influences without any weights and returns the removed bone names.
+pub fn remove_empty_influences(influences: &mut Vec<BoneInfluence>) -> Vec<String> {
+    let mut removed = Vec::new();
+    influences.retain(|i| {
+        if i.vertex_weights.is_empty() {
+            removed.push(i.bone_name.clone());
+            false
+        } else {
+            true
+        }
+    });
+    removed
+}
@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use glam::{EulerRot, Mat4, Quat, Vec3};
+use ssbh_data::anim_data::{
+    AnimData, GroupData, GroupType, NodeData, TrackData, TrackValues, TransformFlags,
+};
+use ssbh_data::mesh_data::{
+    AttributeData, BoneInfluence, MeshData, MeshObjectData, VectorData, VertexWeight,
+};
+use ssbh_data::skel_data::{BillboardType, BoneData, SkelData};
+
+use crate::anim_sampling::{anim_frame_count, find_track, quat, sample_track, vec3, TrackValue};
+use crate::mesh_transform::{bone_world_transform, parent_bone_to_model_space, transform_object};
+use crate::vector_data::{vectors2, vectors3};
+
+/// Writes the bones in `skel` and the triangles for each object in `mesh` to an SMD reference file.
+///
+/// The object name is used as the material name for each triangle.
+/// Vertices are linked to the bones in `bone_influences` with weights normalized to sum to 1.0
+/// since SMD assigns any remaining weight to the parent bone.
+/// Objects without influences use `parent_bone_name` as the parent bone for all vertices
+/// and are transformed from the parent bone's space to model space.
+/// SMD vertices require a parent bone, so a bone named `root` is written if `skel` has no bones.
+/// The V coordinate is flipped to match the bottom left UV origin expected by SMD.
+pub fn write_smd_mesh(mesh: &MeshData, skel: &SkelData) -> Result<String, String> {
+    let root_skel;
+    let skel = if skel.bones.is_empty() {
+        root_skel = SkelData {
+            major_version: skel.major_version,
+            minor_version: skel.minor_version,
+            bones: vec![BoneData {
+                name: "root".to_string(),
+                transform: Mat4::IDENTITY.to_cols_array_2d(),
+                parent_index: None,
+                billboard_type: BillboardType::Disabled,
+            }],
+        };
+        &root_skel
+    } else {
+        skel
+    };
+
+    let mut smd = String::new();
+    write_nodes(&mut smd, skel);
+
+    writeln!(&mut smd, "skeleton").unwrap();
+    writeln!(&mut smd, "time 0").unwrap();
+    for (i, bone) in skel.bones.iter().enumerate() {
+        let (_, rotation, translation) =
+            Mat4::from_cols_array_2d(&bone.transform).to_scale_rotation_translation();
+        write_bone_frame(&mut smd, i, translation, rotation);
+    }
+    writeln!(&mut smd, "end").unwrap();
+
+    let bone_index = |name: &str| {
+        skel.bones
+            .iter()
+            .position(|b| b.name == name)
+            .ok_or_else(|| format!("Bone {name:?} not found in skeleton."))
+    };
+
+    writeln!(&mut smd, "triangles").unwrap();
+    for object in &mesh.objects {
+        let mut object = object.clone();
+        parent_bone_to_model_space(&mut object, skel)?;
+
+        let positions = object
+            .positions
+            .first()
+            .map(|a| vectors3(&a.data))
+            .unwrap_or_default();
+        let normals = object
+            .normals
+            .first()
+            .map(|a| vectors3(&a.data))
+            .unwrap_or_else(|| vec![[0.0; 3]; positions.len()]);
+        let uvs = object
+            .texture_coordinates
+            .first()
+            .map(|a| vectors2(&a.data))
+            .unwrap_or_else(|| vec![[0.0; 2]; positions.len()]);
+
+        if let Some(i) = object
+            .vertex_indices
+            .iter()
+            .find(|i| **i as usize >= positions.len().min(normals.len()).min(uvs.len()))
+        {
+            return Err(format!(
+                "Vertex index {i} is out of range for mesh object {:?}.",
+                object.name
+            ));
+        }
+
+        let mut links: Vec<Vec<(usize, f32)>> = vec![Vec::new(); positions.len()];
+        for influence in &object.bone_influences {
+            let bone = bone_index(&influence.bone_name)?;
+            for w in &influence.vertex_weights {
+                if let Some(vertex_links) = links.get_mut(w.vertex_index as usize) {
+                    if w.vertex_weight > 0.0 {
+                        vertex_links.push((bone, w.vertex_weight));
+                    }
+                }
+            }
+        }
+        // SMD assigns any remaining weight to the parent bone, so normalize the weights.
+        for vertex_links in &mut links {
+            let total: f32 = vertex_links.iter().map(|(_, w)| w).sum();
+            for (_, weight) in vertex_links {
+                *weight /= total;
+            }
+        }
+        let parent = if object.parent_bone_name.is_empty() {
+            0
+        } else {
+            bone_index(&object.parent_bone_name)?
+        };
+
+        for face in object.vertex_indices.chunks_exact(3) {
+            writeln!(&mut smd, "{}", object.name).unwrap();
+            for i in face {
+                let i = *i as usize;
+                let [x, y, z] = positions[i];
+                let [nx, ny, nz] = normals[i];
+                let [u, v] = uvs[i];
+                write!(
+                    &mut smd,
+                    "  {parent} {x} {y} {z} {nx} {ny} {nz} {u} {}",
+                    1.0 - v
+                )
+                .unwrap();
+                if !links[i].is_empty() {
+                    write!(&mut smd, " {}", links[i].len()).unwrap();
+                    for (bone, weight) in &links[i] {
+                        write!(&mut smd, " {bone} {weight}").unwrap();
+                    }
+                }
+                writeln!(&mut smd).unwrap();
+            }
+        }
+    }
+    writeln!(&mut smd, "end").unwrap();
+
+    Ok(smd)
+}
+
+/// Creates a mesh object for each material and a skeleton from the SMD reference file text `smd`.
+///
+/// Vertices that are identical for all attributes and bone links are merged.
+/// Any weight not assigned by the bone links of a vertex is assigned to the vertex's parent bone.
+/// Objects without any bone links use the first parent bone as the `parent_bone_name`
+/// and are transformed to the parent bone's space.
+pub fn read_smd_mesh(smd: &str) -> Result<(MeshData, SkelData), String> {
+    let smd = parse_smd(smd)?;
+
+    let mut skel = SkelData {
+        major_version: 1,
+        minor_version: 0,
+        bones: smd
+            .nodes
+            .iter()
+            .map(|(name, parent)| BoneData {
+                name: name.clone(),
+                transform: Mat4::IDENTITY.to_cols_array_2d(),
+                parent_index: *parent,
+                billboard_type: BillboardType::Disabled,
+            })
+            .collect(),
+    };
+    if let Some(frame) = smd.frames.first() {
+        for (bone, (translation, rotation)) in skel.bones.iter_mut().zip(frame) {
+            bone.transform =
+                Mat4::from_rotation_translation(*rotation, *translation).to_cols_array_2d();
+        }
+    }
+
+    // Group triangles by material while preserving the order they first appear.
+    let mut objects: Vec<SmdObject> = Vec::new();
+    for triangle in smd.triangles {
+        let object = match objects.iter().position(|o| o.name == triangle.material) {
+            Some(i) => &mut objects[i],
+            None => {
+                objects.push(SmdObject::new(triangle.material.clone()));
+                objects.last_mut().unwrap()
+            }
+        };
+        for vertex in triangle.vertices {
+            object.add_vertex(vertex);
+        }
+    }
+
+    let mut mesh = MeshData {
+        major_version: 1,
+        minor_version: 10,
+        objects: objects.into_iter().map(|o| o.mesh_object(&skel)).collect(),
+    };
+    for object in &mut mesh.objects {
+        if !object.parent_bone_name.is_empty() {
+            let transform = bone_world_transform(&skel, &object.parent_bone_name)?;
+            transform_object(object, transform.inverse());
+        }
+    }
+    Ok((mesh, skel))
+}
+
+/// Writes the transforms for each bone in `skel` for every frame of `anim` to an SMD animation file.
+///
+/// Bones without a transform track use the transform from `skel`.
+/// SMD doesn't support scale, so any scaling is not included.
+pub fn write_smd_anim(anim: &AnimData, skel: &SkelData) -> String {
+    let mut smd = String::new();
+    write_nodes(&mut smd, skel);
+
+    writeln!(&mut smd, "skeleton").unwrap();
+    for frame in 0..anim_frame_count(anim) {
+        writeln!(&mut smd, "time {frame}").unwrap();
+        for (i, bone) in skel.bones.iter().enumerate() {
+            let (translation, rotation) =
+                match find_track(anim, &GroupType::Transform, &bone.name, "Transform")
+                    .and_then(|t| sample_track(&t.values, frame as f32))
+                {
+                    Some(TrackValue::Transform(t)) => (vec3(&t.translation), quat(&t.rotation)),
+                    _ => {
+                        let (_, rotation, translation) = Mat4::from_cols_array_2d(&bone.transform)
+                            .to_scale_rotation_translation();
+                        (translation, rotation)
+                    }
+                };
+            write_bone_frame(&mut smd, i, translation, rotation);
+        }
+    }
+    writeln!(&mut smd, "end").unwrap();
+
+    smd
+}
+
+/// Creates an animation with a transform track for each node in the SMD animation file text `smd`.
+///
+/// Bones that are not listed for a frame use the values from the previous frame.
+pub fn read_smd_anim(smd: &str) -> Result<AnimData, String> {
+    let smd = parse_smd(smd)?;
+    if smd.frames.is_empty() {
+        return Err("Expected at least one frame in skeleton.".to_string());
+    }
+
+    let nodes = smd
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| NodeData {
+            name: name.clone(),
+            tracks: vec![TrackData {
+                name: "Transform".to_string(),
+                compensate_scale: false,
+                transform_flags: TransformFlags {
+                    override_translation: false,
+                    override_rotation: false,
+                    override_scale: false,
+                    override_compensate_scale: false,
+                },
+                values: TrackValues::Transform(
+                    smd.frames
+                        .iter()
+                        .map(|frame| {
+                            let (translation, rotation) = frame[i];
+                            crate::anim_sampling::transform(Vec3::ONE, rotation, translation)
+                        })
+                        .collect(),
+                ),
+            }],
+        })
+        .collect();
+
+    Ok(AnimData {
+        major_version: 2,
+        minor_version: 0,
+        groups: vec![GroupData {
+            group_type: GroupType::Transform,
+            nodes,
+        }],
+        final_frame_index: (smd.frames.len() - 1) as f32,
+    })
+}
+
+fn write_nodes(smd: &mut String, skel: &SkelData) {
+    writeln!(smd, "version 1").unwrap();
+    writeln!(smd, "nodes").unwrap();
+    for (i, bone) in skel.bones.iter().enumerate() {
+        let parent = bone.parent_index.map(|p| p as i64).unwrap_or(-1);
+        writeln!(smd, "  {i} \"{}\" {parent}", bone.name).unwrap();
+    }
+    writeln!(smd, "end").unwrap();
+}
+
+fn write_bone_frame(smd: &mut String, index: usize, translation: Vec3, rotation: Quat) {
+    // SMD rotations are applied in X, Y, Z order.
+    let (rz, ry, rx) = rotation.to_euler(EulerRot::ZYX);
+    let [x, y, z] = translation.to_array();
+    writeln!(smd, "  {index} {x} {y} {z} {rx} {ry} {rz}").unwrap();
+}
+
+struct Smd {
+    nodes: Vec<(String, Option<usize>)>,
+    frames: Vec<Vec<(Vec3, Quat)>>,
+    triangles: Vec<SmdTriangle>,
+}
+
+struct SmdTriangle {
+    material: String,
+    vertices: Vec<SmdVertex>,
+}
+
+#[derive(Clone)]
+struct SmdVertex {
+    parent: usize,
+    position: [f32; 3],
+    normal: [f32; 3],
+    uv: [f32; 2],
+    links: Vec<(usize, f32)>,
+}
+
+impl SmdVertex {
+    // Floats aren't hashable, so compare vertices using their bits.
+    fn key(&self) -> Vec<u32> {
+        let mut key = vec![self.parent as u32];
+        key.extend(self.position.iter().map(|f| f.to_bits()));
+        key.extend(self.normal.iter().map(|f| f.to_bits()));
+        key.extend(self.uv.iter().map(|f| f.to_bits()));
+        for (bone, weight) in &self.links {
+            key.extend([*bone as u32, weight.to_bits()]);
+        }
+        key
+    }
+}
+
+fn parse_smd(smd: &str) -> Result<Smd, String> {
+    let mut nodes = Vec::new();
+    let mut frames: Vec<Vec<(Vec3, Quat)>> = Vec::new();
+    let mut triangles = Vec::new();
+
+    let mut lines = smd
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim()))
+        .filter(|(_, l)| !l.is_empty() && !l.starts_with("//"));
+    while let Some((line_number, line)) = lines.next() {
+        match line {
+            "nodes" => {
+                for (line_number, line) in lines.by_ref() {
+                    if line == "end" {
+                        break;
+                    }
+                    nodes.push(parse_node(line, nodes.len(), line_number)?);
+                }
+            }
+            "skeleton" => {
+                for (line_number, line) in lines.by_ref() {
+                    if line == "end" {
+                        break;
+                    }
+                    if line.starts_with("time") {
+                        // Bones can be omitted from a frame if they don't change.
+                        let previous = frames
+                            .last()
+                            .cloned()
+                            .unwrap_or_else(|| vec![(Vec3::ZERO, Quat::IDENTITY); nodes.len()]);
+                        frames.push(previous);
+                        continue;
+                    }
+
+                    let values = parse_values(line, line_number)?;
+                    let frame = frames
+                        .last_mut()
+                        .ok_or_else(|| format!("Expected time before line {line_number}."))?;
+                    let (index, transform) = parse_bone_frame(&values, line_number)?;
+                    *frame.get_mut(index).ok_or_else(|| {
+                        format!("Invalid bone index {index} on line {line_number}.")
+                    })? = transform;
+                }
+            }
+            "triangles" => {
+                while let Some((_, material)) = lines.next() {
+                    if material == "end" {
+                        break;
+                    }
+                    let vertices = (0..3)
+                        .map(|_| {
+                            let (line_number, line) = lines
+                                .next()
+                                .ok_or_else(|| format!("Expected 3 vertices for {material:?}."))?;
+                            parse_vertex(line, nodes.len(), line_number)
+                        })
+                        .collect::<Result<Vec<_>, String>>()?;
+                    triangles.push(SmdTriangle {
+                        material: material.to_string(),
+                        vertices,
+                    });
+                }
+            }
+            _ if line.starts_with("version") => (),
+            _ => return Err(format!("Unexpected line {line:?} on line {line_number}.")),
+        }
+    }
+
+    Ok(Smd {
+        nodes,
+        frames,
+        triangles,
+    })
+}
+
+fn parse_node(
+    line: &str,
+    index: usize,
+    line_number: usize,
+) -> Result<(String, Option<usize>), String> {
+    // Names are quoted and may contain spaces.
+    let invalid = || format!("Invalid node on line {line_number}.");
+    let mut parts = line.splitn(3, '"');
+    let id: usize = parts
+        .next()
+        .and_then(|p| p.trim().parse().ok())
+        .ok_or_else(invalid)?;
+    let name = parts.next().ok_or_else(invalid)?;
+    let parent: i64 = parts
+        .next()
+        .and_then(|p| p.trim().parse().ok())
+        .ok_or_else(invalid)?;
+    if id != index {
+        return Err(format!(
+            "Expected node index {index} but found {id} on line {line_number}."
+        ));
+    }
+    let parent = usize::try_from(parent).ok();
+    if parent.is_some_and(|p| p >= index) {
+        return Err(format!(
+            "Parent index {parent:?} must be less than the node index {index} on line {line_number}."
+        ));
+    }
+    Ok((name.to_string(), parent))
+}
+
+fn parse_values(line: &str, line_number: usize) -> Result<Vec<f32>, String> {
+    line.split_whitespace()
+        .map(|v| {
+            v.parse()
+                .map_err(|_| format!("Invalid value {v:?} on line {line_number}."))
+        })
+        .collect()
+}
+
+fn parse_bone_frame(values: &[f32], line_number: usize) -> Result<(usize, (Vec3, Quat)), String> {
+    let [index, x, y, z, rx, ry, rz] = values else {
+        return Err(format!("Expected 7 values on line {line_number}."));
+    };
+    let index = parse_index(*index, "bone index", line_number)?;
+    let translation = Vec3::new(*x, *y, *z);
+    let rotation = Quat::from_euler(EulerRot::ZYX, *rz, *ry, *rx);
+    Ok((index, (translation, rotation)))
+}
+
+/// Converts `value` to an index and rejects values that aren't non-negative integers.
+fn parse_index(value: f32, name: &str, line_number: usize) -> Result<usize, String> {
+    if value.is_finite() && value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f32 {
+        Ok(value as usize)
+    } else {
+        Err(format!("Invalid {name} {value} on line {line_number}."))
+    }
+}
+
+fn parse_vertex(line: &str, bone_count: usize, line_number: usize) -> Result<SmdVertex, String> {
+    let values = parse_values(line, line_number)?;
+    if values.len() < 9 {
+        return Err(format!("Expected at least 9 values on line {line_number}."));
+    }
+    let bone = |value: f32| {
+        let index = parse_index(value, "bone index", line_number)?;
+        if index < bone_count {
+            Ok(index)
+        } else {
+            Err(format!("Invalid bone index {index} on line {line_number}."))
+        }
+    };
+    let parent = bone(values[0])?;
+
+    let mut links = Vec::new();
+    if let Some(count) = values.get(9) {
+        let count = parse_index(*count, "bone link count", line_number)?;
+        let link_values = &values[10..];
+        if count.checked_mul(2) != Some(link_values.len()) {
+            return Err(format!(
+                "Expected {count} bone links on line {line_number}."
+            ));
+        }
+        for link in link_values.chunks_exact(2) {
+            links.push((bone(link[0])?, link[1]));
+        }
+
+        // SMD assigns any weight not in the links to the parent bone.
+        // Ignore small differences from rounding when writing the weights.
+        let remainder = 1.0 - links.iter().map(|(_, w)| w).sum::<f32>();
+        if !links.is_empty() && remainder > 1e-5 {
+            match links.iter_mut().find(|(b, _)| *b == parent) {
+                Some((_, weight)) => *weight += remainder,
+                None => links.push((parent, remainder)),
+            }
+        }
+    }
+
+    Ok(SmdVertex {
+        parent,
+        position: [values[1], values[2], values[3]],
+        normal: [values[4], values[5], values[6]],
+        uv: [values[7], 1.0 - values[8]],
+        links,
+    })
+}
+
+struct SmdObject {
+    name: String,
+    vertices: Vec<SmdVertex>,
+    vertex_indices: Vec<u32>,
+    vertex_lookup: HashMap<Vec<u32>, u32>,
+}
+
+impl SmdObject {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            vertices: Vec::new(),
+            vertex_indices: Vec::new(),
+            vertex_lookup: HashMap::new(),
+        }
+    }
+
+    fn add_vertex(&mut self, vertex: SmdVertex) {
+        let index = *self.vertex_lookup.entry(vertex.key()).or_insert_with(|| {
+            self.vertices.push(vertex);
+            self.vertices.len() as u32 - 1
+        });
+        self.vertex_indices.push(index);
+    }
+
+    fn mesh_object(self, skel: &SkelData) -> MeshObjectData {
+        let has_links = self.vertices.iter().any(|v| !v.links.is_empty());
+
+        let mut parent_bone_name = String::new();
+        let mut bone_influences = Vec::new();
+        if has_links {
+            // Vertices without links are weighted entirely to their parent bone.
+            let mut weights: Vec<Vec<VertexWeight>> = vec![Vec::new(); skel.bones.len()];
+            for (i, vertex) in self.vertices.iter().enumerate() {
+                let links = if vertex.links.is_empty() {
+                    vec![(vertex.parent, 1.0)]
+                } else {
+                    vertex.links.clone()
+                };
+                for (bone, weight) in links {
+                    weights[bone].push(VertexWeight {
+                        vertex_index: i as u32,
+                        vertex_weight: weight,
+                    });
+                }
+            }
+            bone_influences = skel
+                .bones
+                .iter()
+                .zip(weights)
+                .filter(|(_, w)| !w.is_empty())
+                .map(|(bone, vertex_weights)| BoneInfluence {
+                    bone_name: bone.name.clone(),
+                    vertex_weights,
+                })
+                .collect();
+        } else if let Some(vertex) = self.vertices.first() {
+            parent_bone_name = skel.bones[vertex.parent].name.clone();
+        }
+
+        MeshObjectData {
+            name: self.name,
+            subindex: 0,
+            parent_bone_name,
+            disable_depth_test: false,
+            disable_depth_write: false,
+            sort_bias: 0,
+            vertex_indices: self.vertex_indices,
+            positions: vec![AttributeData {
+                name: "Position0".to_string(),
+                data: VectorData::Vector3(self.vertices.iter().map(|v| v.position).collect()),
+            }],
+            normals: vec![AttributeData {
+                name: "Normal0".to_string(),
+                data: VectorData::Vector3(self.vertices.iter().map(|v| v.normal).collect()),
+            }],
+            binormals: Vec::new(),
+            tangents: Vec::new(),
+            texture_coordinates: vec![AttributeData {
+                name: "map1".to_string(),
+                data: VectorData::Vector2(self.vertices.iter().map(|v| v.uv).collect()),
+            }],
+            color_sets: Vec::new(),
+            bone_influences,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use ssbh_data::mesh_data::{AttributeData, MeshObjectData, VectorData};
+
+/// Converts `data` to 2 component vectors by removing or zero padding components.
+pub fn vectors2(data: &VectorData) -> Vec<[f32; 2]> {
+    match data {
+        VectorData::Vector2(v) => v.clone(),
+        VectorData::Vector3(v) => v.iter().map(|[x, y, _]| [*x, *y]).collect(),
+        VectorData::Vector4(v) => v.iter().map(|[x, y, _, _]| [*x, *y]).collect(),
+    }
+}
+
+/// Converts `data` to 3 component vectors by removing or zero padding components.
+pub fn vectors3(data: &VectorData) -> Vec<[f32; 3]> {
+    match data {
+        VectorData::Vector2(v) => v.iter().map(|[x, y]| [*x, *y, 0.0]).collect(),
+        VectorData::Vector3(v) => v.clone(),
+        VectorData::Vector4(v) => v.iter().map(|[x, y, z, _]| [*x, *y, *z]).collect(),
+    }
+}
+
+/// Converts `data` to 4 component vectors by removing components or padding with `1.0` for W.
+pub fn vectors4(data: &VectorData) -> Vec<[f32; 4]> {
+    match data {
+        VectorData::Vector2(v) => v.iter().map(|[x, y]| [*x, *y, 0.0, 1.0]).collect(),
+        VectorData::Vector3(v) => v.iter().map(|[x, y, z]| [*x, *y, *z, 1.0]).collect(),
+        VectorData::Vector4(v) => v.clone(),
+    }
+}
+
+/// The number of vectors in `data`.
+pub fn vector_count(data: &VectorData) -> usize {
+    match data {
+        VectorData::Vector2(v) => v.len(),
+        VectorData::Vector3(v) => v.len(),
+        VectorData::Vector4(v) => v.len(),
+    }
+}
+
+/// The components for each vector in `data` and the number of components per vector.
+pub fn vector_components(data: &VectorData) -> (Vec<f32>, usize) {
+    match data {
+        VectorData::Vector2(v) => (v.iter().flatten().copied().collect(), 2),
+        VectorData::Vector3(v) => (v.iter().flatten().copied().collect(), 3),
+        VectorData::Vector4(v) => (v.iter().flatten().copied().collect(), 4),
+    }
+}
+
+/// Creates new vector data with the vectors at `indices` in order.
+pub fn select_vectors(data: &VectorData, indices: &[usize]) -> VectorData {
+    match data {
+        VectorData::Vector2(v) => VectorData::Vector2(indices.iter().map(|i| v[*i]).collect()),
+        VectorData::Vector3(v) => VectorData::Vector3(indices.iter().map(|i| v[*i]).collect()),
+        VectorData::Vector4(v) => VectorData::Vector4(indices.iter().map(|i| v[*i]).collect()),
+    }
+}
+
+/// All the vertex attributes for `object` in a consistent order.
+pub fn attributes(object: &MeshObjectData) -> impl Iterator<Item = &AttributeData> {
+    object
+        .positions
+        .iter()
+        .chain(&object.normals)
+        .chain(&object.binormals)
+        .chain(&object.tangents)
+        .chain(&object.texture_coordinates)
+        .chain(&object.color_sets)
+}
+
+/// All the vertex attributes for `object` in the same order as [attributes].
+pub fn attributes_mut(object: &mut MeshObjectData) -> impl Iterator<Item = &mut AttributeData> {
+    object
+        .positions
+        .iter_mut()
+        .chain(&mut object.normals)
+        .chain(&mut object.binormals)
+        .chain(&mut object.tangents)
+        .chain(&mut object.texture_coordinates)
+        .chain(&mut object.color_sets)
+}
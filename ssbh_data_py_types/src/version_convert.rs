@@ -0,0 +1,443 @@
+use std::io::Cursor;
+
+use ssbh_data::anim_data::{AnimData, TrackData};
+use ssbh_data::matl_data::{MatlData, MatlEntryData};
+use ssbh_data::mesh_data::{AttributeData, MeshData, MeshObjectData};
+use ssbh_data::modl_data::ModlData;
+use ssbh_data::skel_data::SkelData;
+
+/// A format that can be saved with a supported set of versions.
+pub trait SaveVersion: Clone {
+    const FORMAT: &'static str;
+    /// The versions that can be saved.
+    const VERSIONS: &'static [(u16, u16)];
+
+    fn version(&self) -> (u16, u16);
+
+    fn set_version(&mut self, version: (u16, u16));
+
+    /// Saves the data in memory and reads it back.
+    fn save_read(&self) -> Result<Self, String>;
+
+    /// A message for each difference in `saved` compared to `self`.
+    fn changes(&self, saved: &Self) -> Vec<String>;
+}
+
+macro_rules! save_version_impl {
+    ($ty:ty, $format:literal, $versions:expr, $changes:path) => {
+        impl SaveVersion for $ty {
+            const FORMAT: &'static str = $format;
+            const VERSIONS: &'static [(u16, u16)] = &$versions;
+
+            fn version(&self) -> (u16, u16) {
+                (self.major_version, self.minor_version)
+            }
+
+            fn set_version(&mut self, version: (u16, u16)) {
+                self.major_version = version.0;
+                self.minor_version = version.1;
+            }
+
+            fn save_read(&self) -> Result<Self, String> {
+                let mut writer = Cursor::new(Vec::new());
+                self.write(&mut writer).map_err(|e| {
+                    format!(
+                        "Failed to save {} version {}.{}: {e}",
+                        $format, self.major_version, self.minor_version
+                    )
+                })?;
+                writer.set_position(0);
+                <$ty>::read(&mut writer).map_err(|e| {
+                    format!(
+                        "Failed to read {} version {}.{}: {e}",
+                        $format, self.major_version, self.minor_version
+                    )
+                })
+            }
+
+            fn changes(&self, saved: &Self) -> Vec<String> {
+                $changes(self, saved)
+            }
+        }
+    };
+}
+
+save_version_impl!(MeshData, "mesh", [(1, 8), (1, 9), (1, 10)], mesh_changes);
+save_version_impl!(MatlData, "matl", [(1, 5), (1, 6)], matl_changes);
+save_version_impl!(SkelData, "skel", [(1, 0)], skel_changes);
+// Version 2.1 can be read but only version 2.0 can be saved.
+save_version_impl!(AnimData, "anim", [(2, 0)], anim_changes);
+save_version_impl!(ModlData, "modl", [(1, 7)], modl_changes);
+
+/// Checks that `version` can be saved for the format `T`.
+pub fn check_version<T: SaveVersion>(version: (u16, u16)) -> Result<(), String> {
+    if T::VERSIONS.contains(&version) {
+        Ok(())
+    } else {
+        let expected: Vec<_> = T::VERSIONS
+            .iter()
+            .map(|(major, minor)| format!("{major}.{minor}"))
+            .collect();
+        Err(format!(
+            "Unsupported {} version {}.{}. Expected one of {}.",
+            T::FORMAT,
+            version.0,
+            version.1,
+            expected.join(", ")
+        ))
+    }
+}
+
+/// Checks that `version` can be saved for `data`
+/// and returns a message for each change when saving with `version` instead of the current version.
+///
+/// The changes are found by saving and reading the data in memory for both versions.
+/// Data for a current version that can't be saved is compared with the data saved for `version`.
+pub fn convert_version<T: SaveVersion>(
+    data: &T,
+    version: (u16, u16),
+) -> Result<Vec<String>, String> {
+    check_version::<T>(version)?;
+
+    let current = data.version();
+    if current == version {
+        return Ok(Vec::new());
+    }
+
+    let mut changes = Vec::new();
+    let expected = if T::VERSIONS.contains(&current) {
+        data.save_read()?
+    } else {
+        changes.push(format!(
+            "Data specific to {} version {}.{} will not be saved for version {}.{}.",
+            T::FORMAT,
+            current.0,
+            current.1,
+            version.0,
+            version.1
+        ));
+        data.clone()
+    };
+
+    let mut converted = data.clone();
+    converted.set_version(version);
+    let saved = converted.save_read()?;
+
+    changes.extend(expected.changes(&saved));
+    Ok(changes)
+}
+
+/// Adds a message to `changes` for each item in `expected` that is removed or changed in `saved`
+/// and each item in `saved` that is added using `name` to find matching items.
+fn item_changes<T: PartialEq>(
+    kind: &str,
+    expected: &[T],
+    saved: &[T],
+    name: impl Fn(&T) -> String,
+    changes: &mut Vec<String>,
+) {
+    for item in expected {
+        match saved.iter().find(|s| name(s) == name(item)) {
+            Some(s) if s != item => changes.push(format!("{kind} {} will be changed.", name(item))),
+            Some(_) => (),
+            None => changes.push(format!("{kind} {} will be removed.", name(item))),
+        }
+    }
+    for item in saved {
+        if !expected.iter().any(|e| name(e) == name(item)) {
+            changes.push(format!("{kind} {} will be added.", name(item)));
+        }
+    }
+}
+
+fn mesh_changes(expected: &MeshData, saved: &MeshData) -> Vec<String> {
+    let mut changes = Vec::new();
+    for (i, object) in expected.objects.iter().enumerate() {
+        match saved.objects.get(i) {
+            Some(saved_object) => mesh_object_changes(object, saved_object, &mut changes),
+            None => changes.push(format!("Mesh object {:?} will be removed.", object.name)),
+        }
+    }
+    for object in saved.objects.iter().skip(expected.objects.len()) {
+        changes.push(format!("Mesh object {:?} will be added.", object.name));
+    }
+    changes
+}
+
+fn mesh_object_changes(
+    expected: &MeshObjectData,
+    saved: &MeshObjectData,
+    changes: &mut Vec<String>,
+) {
+    let name = &expected.name;
+
+    // Attributes may be renamed, so compare attributes in the same position in each list.
+    let attribute_lists = [
+        (&expected.positions, &saved.positions),
+        (&expected.normals, &saved.normals),
+        (&expected.binormals, &saved.binormals),
+        (&expected.tangents, &saved.tangents),
+        (&expected.texture_coordinates, &saved.texture_coordinates),
+        (&expected.color_sets, &saved.color_sets),
+    ];
+    for (expected_list, saved_list) in attribute_lists {
+        for (i, a) in expected_list.iter().enumerate() {
+            match saved_list.get(i) {
+                Some(s) => attribute_changes(name, a, s, changes),
+                None => changes.push(format!(
+                    "Attribute {:?} for mesh object {name:?} will be removed.",
+                    a.name
+                )),
+            }
+        }
+        for s in saved_list.iter().skip(expected_list.len()) {
+            changes.push(format!(
+                "Attribute {:?} for mesh object {name:?} will be added.",
+                s.name
+            ));
+        }
+    }
+
+    let fields = [
+        ("name", expected.name != saved.name),
+        ("subindex", expected.subindex != saved.subindex),
+        (
+            "parent_bone_name",
+            expected.parent_bone_name != saved.parent_bone_name,
+        ),
+        (
+            "disable_depth_test",
+            expected.disable_depth_test != saved.disable_depth_test,
+        ),
+        (
+            "disable_depth_write",
+            expected.disable_depth_write != saved.disable_depth_write,
+        ),
+        ("sort_bias", expected.sort_bias != saved.sort_bias),
+        (
+            "vertex_indices",
+            expected.vertex_indices != saved.vertex_indices,
+        ),
+    ];
+    for (field, changed) in fields {
+        if changed {
+            changes.push(format!(
+                "Field {field} for mesh object {name:?} will be changed."
+            ));
+        }
+    }
+
+    item_changes(
+        &format!("Bone influence for mesh object {name:?} with bone"),
+        &expected.bone_influences,
+        &saved.bone_influences,
+        |i| format!("{:?}", i.bone_name),
+        changes,
+    );
+}
+
+fn attribute_changes(
+    object_name: &str,
+    expected: &AttributeData,
+    saved: &AttributeData,
+    changes: &mut Vec<String>,
+) {
+    let name = &expected.name;
+    if saved.name != *name {
+        changes.push(format!(
+            "Attribute {name:?} for mesh object {object_name:?} will be renamed to {:?}.",
+            saved.name
+        ));
+    }
+
+    let (_, expected_count) = crate::vector_data::vector_components(&expected.data);
+    let (_, saved_count) = crate::vector_data::vector_components(&saved.data);
+    if saved_count != expected_count {
+        changes.push(format!(
+            "Attribute {name:?} for mesh object {object_name:?} will be converted from {expected_count} to {saved_count} components."
+        ));
+    } else if saved.data != expected.data {
+        changes.push(format!(
+            "Attribute {name:?} for mesh object {object_name:?} will be saved with different values or precision."
+        ));
+    }
+}
+
+fn matl_changes(expected: &MatlData, saved: &MatlData) -> Vec<String> {
+    let mut changes = Vec::new();
+    for entry in &expected.entries {
+        match saved
+            .entries
+            .iter()
+            .find(|s| s.material_label == entry.material_label)
+        {
+            Some(s) => matl_entry_changes(entry, s, &mut changes),
+            None => changes.push(format!(
+                "Material {:?} will be removed.",
+                entry.material_label
+            )),
+        }
+    }
+    for entry in &saved.entries {
+        if !expected
+            .entries
+            .iter()
+            .any(|e| e.material_label == entry.material_label)
+        {
+            changes.push(format!(
+                "Material {:?} will be added.",
+                entry.material_label
+            ));
+        }
+    }
+    changes
+}
+
+fn matl_entry_changes(expected: &MatlEntryData, saved: &MatlEntryData, changes: &mut Vec<String>) {
+    let label = &expected.material_label;
+    if saved.shader_label != expected.shader_label {
+        changes.push(format!(
+            "Shader label {:?} for material {label:?} will be changed to {:?}.",
+            expected.shader_label, saved.shader_label
+        ));
+    }
+
+    let kind = format!("Parameter for material {label:?}");
+    item_changes(
+        &kind,
+        &expected.blend_states,
+        &saved.blend_states,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.floats,
+        &saved.floats,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.booleans,
+        &saved.booleans,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.vectors,
+        &saved.vectors,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.rasterizer_states,
+        &saved.rasterizer_states,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.samplers,
+        &saved.samplers,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.textures,
+        &saved.textures,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+    item_changes(
+        &kind,
+        &expected.uv_transforms,
+        &saved.uv_transforms,
+        |p| p.param_id.to_string(),
+        changes,
+    );
+}
+
+fn skel_changes(expected: &SkelData, saved: &SkelData) -> Vec<String> {
+    let mut changes = Vec::new();
+    item_changes(
+        "Bone",
+        &expected.bones,
+        &saved.bones,
+        |b| format!("{:?}", b.name),
+        &mut changes,
+    );
+    changes
+}
+
+fn anim_changes(expected: &AnimData, saved: &AnimData) -> Vec<String> {
+    let mut changes = Vec::new();
+    if saved.final_frame_index != expected.final_frame_index {
+        changes.push(format!(
+            "Final frame index {} will be changed to {}.",
+            expected.final_frame_index, saved.final_frame_index
+        ));
+    }
+
+    // Compare individual tracks since groups and nodes only identify the tracks.
+    item_changes(
+        "Track",
+        &anim_tracks(expected),
+        &anim_tracks(saved),
+        |(name, _)| name.clone(),
+        &mut changes,
+    );
+    changes
+}
+
+fn anim_tracks(anim: &AnimData) -> Vec<(String, &TrackData)> {
+    anim.groups
+        .iter()
+        .flat_map(|g| {
+            g.nodes.iter().flat_map(move |n| {
+                n.tracks
+                    .iter()
+                    .map(move |t| (format!("{} {:?} {:?}", g.group_type, n.name, t.name), t))
+            })
+        })
+        .collect()
+}
+
+fn modl_changes(expected: &ModlData, saved: &ModlData) -> Vec<String> {
+    let mut changes = Vec::new();
+    let fields = [
+        ("model_name", expected.model_name != saved.model_name),
+        (
+            "skeleton_file_name",
+            expected.skeleton_file_name != saved.skeleton_file_name,
+        ),
+        (
+            "material_file_names",
+            expected.material_file_names != saved.material_file_names,
+        ),
+        (
+            "animation_file_name",
+            expected.animation_file_name != saved.animation_file_name,
+        ),
+        (
+            "mesh_file_name",
+            expected.mesh_file_name != saved.mesh_file_name,
+        ),
+    ];
+    for (field, changed) in fields {
+        if changed {
+            changes.push(format!("Field {field} will be changed."));
+        }
+    }
+    item_changes(
+        "Entry for mesh object",
+        &expected.entries,
+        &saved.entries,
+        |e| format!("{:?} {}", e.mesh_object_name, e.mesh_object_subindex),
+        &mut changes,
+    );
+    changes
+}